@@ -14,18 +14,22 @@ mod test;
 #[cfg(test)]
 mod test_krn03;
 
+use soroban_sdk::xdr::ToXdr;
 use soroban_sdk::{
-    contract, contracterror, contractevent, contractimpl, Address, BytesN, Env, IntoVal, String,
-    Symbol, Vec,
+    contract, contracterror, contractevent, contractimpl, Address, Bytes, BytesN, Env, IntoVal,
+    String, Symbol, Vec,
 };
 
+pub use proposal::VoteType;
 use proposal::{Action, Proposal, ProposalState};
 use storage::{
     acquire_lock, extend_instance_ttl, get_config, get_proposal, get_proposal_count, get_valocracy,
-    has_voted, is_locked, release_lock, set_config, set_proposal, set_proposal_count,
-    set_valocracy, set_vote,
+    get_voting_key, has_voted, is_locked, is_nonce_used, release_lock, remove_voting_key,
+    set_config, set_nonce_used, set_proposal, set_proposal_count, set_valocracy, set_vote,
+    set_voting_key,
 };
 use types::GovernanceConfig;
+pub use types::{VoteMessage, VotingKey};
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -44,6 +48,15 @@ pub enum GovernorError {
     InvalidProposalState = 11,
     NotAMember = 12,
     ReentrancyDetected = 13,
+    ProposalCanceled = 14,
+    ProposalNotQueued = 15,
+    TimelockNotReady = 16,
+    ProposalExpired = 17,
+    VotingKeyNotSet = 18,
+    NonceUsed = 19,
+    SignatureExpired = 20,
+    VoteDelegated = 21,
+    VotingKeyExpired = 22,
 }
 
 // Contract events (Soroban SDK >= 25).
@@ -61,18 +74,49 @@ pub enum GovernorEvent {
         proposal_id: u64,
         #[topic]
         voter: Address,
-        support: bool,
+        vote: VoteType,
         voting_power: u64,
     },
+    VoteCastWithReason {
+        #[topic]
+        proposal_id: u64,
+        #[topic]
+        voter: Address,
+        vote: VoteType,
+        voting_power: u64,
+        reason: String,
+    },
+    VotingKeyUpdate {
+        #[topic]
+        voter: Address,
+        public_key: BytesN<32>,
+        expires_at: u64,
+    },
+    VotingKeyRemoved {
+        #[topic]
+        voter: Address,
+    },
+    ProposalQueued {
+        #[topic]
+        proposal_id: u64,
+        eta: u64,
+    },
     ProposalExecuted {
         #[topic]
         proposal_id: u64,
     },
+    ProposalCanceled {
+        #[topic]
+        proposal_id: u64,
+    },
     ContractUpgraded {
         new_wasm_hash: BytesN<32>,
     },
 }
 
+/// Version of the signed vote format. Bumped whenever `VoteMessage` changes.
+pub const VOTE_SIGNATURE_VERSION: u32 = 1;
+
 #[contract]
 pub struct GovernorContract;
 
@@ -152,8 +196,8 @@ impl GovernorContract {
         // KRN-03: Snapshot total Mana supply for participation threshold
         let total_mana: u64 = env.invoke_contract(
             &valocracy,
-            &Symbol::new(&env, "total_mana"),
-            ().into_val(&env),
+            &Symbol::new(&env, "total_mana_at"),
+            (current_time,).into_val(&env),
         );
 
         let proposal = Proposal {
@@ -165,7 +209,11 @@ impl GovernorContract {
             end_time: current_time + config.voting_delay + config.voting_period,
             for_votes: 0,
             against_votes: 0,
+            abstain_votes: 0,
             executed: false,
+            canceled: false,
+            eta: 0,
+            grace_period: 0,
             actions,
             total_mana_at_creation: total_mana, // KRN-03
         };
@@ -190,62 +238,154 @@ impl GovernorContract {
         env: Env,
         voter: Address,
         proposal_id: u64,
-        support: bool,
+        vote: VoteType,
     ) -> Result<u64, GovernorError> {
-        let mut proposal =
-            get_proposal(&env, proposal_id).ok_or(GovernorError::ProposalNotFound)?;
+        voter.require_auth();
 
-        let current_time = env.ledger().timestamp();
+        Self::record_vote(&env, &voter, proposal_id, vote)
+    }
 
-        if current_time < proposal.start_time {
-            return Err(GovernorError::VotingNotStarted);
+    /// Cast a vote with a reason. The reason is only emitted in an event, not stored.
+    pub fn cast_vote_with_reason(
+        env: Env,
+        voter: Address,
+        proposal_id: u64,
+        vote: VoteType,
+        reason: String,
+    ) -> Result<u64, GovernorError> {
+        voter.require_auth();
+
+        let voting_power = Self::record_vote(&env, &voter, proposal_id, vote)?;
+
+        GovernorEvent::VoteCastWithReason {
+            proposal_id,
+            voter,
+            vote,
+            voting_power,
+            reason,
         }
-        if current_time > proposal.end_time {
-            return Err(GovernorError::VotingEnded);
+        .publish(&env);
+
+        Ok(voting_power)
+    }
+
+    /// Register the ed25519 key whose signatures `cast_vote_by_sig` accepts for `voter`.
+    /// Replaces any key registered before; the key is rejected after `expires_at`.
+    pub fn set_voting_key(
+        env: Env,
+        voter: Address,
+        public_key: BytesN<32>,
+        expires_at: u64,
+    ) -> Result<(), GovernorError> {
+        voter.require_auth();
+
+        if expires_at <= env.ledger().timestamp() {
+            return Err(GovernorError::VotingKeyExpired);
         }
 
-        if has_voted(&env, proposal_id, &voter) {
-            return Err(GovernorError::AlreadyVoted);
+        let voting_key = VotingKey {
+            public_key: public_key.clone(),
+            expires_at,
+        };
+        set_voting_key(&env, &voter, &voting_key);
+
+        GovernorEvent::VotingKeyUpdate {
+            voter,
+            public_key,
+            expires_at,
         }
+        .publish(&env);
+        Ok(())
+    }
 
-        if is_locked(&env) {
-            return Err(GovernorError::ReentrancyDetected);
+    /// Remove the voting key of `voter`, so only votes it casts itself are accepted.
+    pub fn remove_voting_key(env: Env, voter: Address) -> Result<(), GovernorError> {
+        voter.require_auth();
+
+        get_voting_key(&env, &voter).ok_or(GovernorError::VotingKeyNotSet)?;
+        remove_voting_key(&env, &voter);
+
+        GovernorEvent::VotingKeyRemoved { voter }.publish(&env);
+        Ok(())
+    }
+
+    /// Get the voting key registered for an account
+    pub fn voting_key_of(env: Env, voter: Address) -> Option<VotingKey> {
+        get_voting_key(&env, &voter)
+    }
+
+    /// Cast a vote relayed on behalf of `voter`.
+    ///
+    /// The voter's registered key signs a `VoteMessage` (see `vote_payload`),
+    /// so anyone can submit the transaction and pay its fee.
+    pub fn cast_vote_by_sig(
+        env: Env,
+        voter: Address,
+        proposal_id: u64,
+        vote: VoteType,
+        nonce: u64,
+        expiry: u64,
+        signature: BytesN<64>,
+    ) -> Result<u64, GovernorError> {
+        let voting_key = get_voting_key(&env, &voter).ok_or(GovernorError::VotingKeyNotSet)?;
+        if env.ledger().timestamp() > voting_key.expires_at {
+            return Err(GovernorError::VotingKeyExpired);
         }
-        acquire_lock(&env);
 
-        // KRN-03 FIX: Get voting power at proposal CREATION time (snapshot)
-        // This prevents "buy-in" during voting delay and ensures fair snapshot timing
-        let valocracy_addr = get_valocracy(&env).ok_or(GovernorError::NotInitialized)?;
-        let voting_power =
-            Self::get_voting_power_at(&env, &valocracy_addr, &voter, proposal.creation_time);
+        if env.ledger().timestamp() > expiry {
+            return Err(GovernorError::SignatureExpired);
+        }
 
-        if voting_power == 0 {
-            release_lock(&env);
-            return Err(GovernorError::NoVotingPower);
+        if is_nonce_used(&env, &voter, nonce) {
+            return Err(GovernorError::NonceUsed);
         }
+        set_nonce_used(&env, &voter, nonce);
 
-        if support {
-            proposal.for_votes += voting_power;
-        } else {
-            proposal.against_votes += voting_power;
+        let payload = Self::vote_message(&env, &voter, proposal_id, vote, nonce, expiry);
+        env.crypto()
+            .ed25519_verify(&voting_key.public_key, &payload, &signature);
+
+        Self::record_vote(&env, &voter, proposal_id, vote)
+    }
+
+    /// Get the exact bytes a voter's key must sign for `cast_vote_by_sig`
+    pub fn vote_payload(
+        env: Env,
+        voter: Address,
+        proposal_id: u64,
+        vote: VoteType,
+        nonce: u64,
+        expiry: u64,
+    ) -> Bytes {
+        Self::vote_message(&env, &voter, proposal_id, vote, nonce, expiry)
+    }
+
+    /// Queue a succeeded proposal in the timelock.
+    /// The proposal becomes executable once `execution_delay` has elapsed.
+    pub fn queue(env: Env, proposal_id: u64) -> Result<u64, GovernorError> {
+        let mut proposal =
+            get_proposal(&env, proposal_id).ok_or(GovernorError::ProposalNotFound)?;
+
+        let state = Self::get_proposal_state(env.clone(), proposal_id)?;
+        if state != ProposalState::Succeeded {
+            return Err(GovernorError::ProposalNotSucceeded);
         }
 
+        let config = get_config(&env).ok_or(GovernorError::NotInitialized)?;
+        let eta = env.ledger().timestamp() + config.execution_delay;
+
+        proposal.eta = eta;
+        proposal.grace_period = config.grace_period;
         set_proposal(&env, proposal_id, &proposal);
-        set_vote(&env, proposal_id, &voter, support);
 
-        GovernorEvent::VoteCast {
-            proposal_id,
-            voter,
-            support,
-            voting_power,
-        }
-        .publish(&env);
+        extend_instance_ttl(&env);
 
-        release_lock(&env);
-        Ok(voting_power)
+        GovernorEvent::ProposalQueued { proposal_id, eta }.publish(&env);
+
+        Ok(eta)
     }
 
-    /// Execute a succeeded proposal
+    /// Execute a queued proposal whose timelock has elapsed
     pub fn execute(env: Env, proposal_id: u64) -> Result<(), GovernorError> {
         let mut proposal =
             get_proposal(&env, proposal_id).ok_or(GovernorError::ProposalNotFound)?;
@@ -254,9 +394,19 @@ impl GovernorContract {
             return Err(GovernorError::ProposalAlreadyExecuted);
         }
 
+        if proposal.canceled {
+            return Err(GovernorError::ProposalCanceled);
+        }
+
         let state = Self::get_proposal_state(env.clone(), proposal_id)?;
-        if state != ProposalState::Succeeded {
-            return Err(GovernorError::ProposalNotSucceeded);
+        match state {
+            ProposalState::Queued => {}
+            ProposalState::Expired => return Err(GovernorError::ProposalExpired),
+            _ => return Err(GovernorError::ProposalNotQueued),
+        }
+
+        if env.ledger().timestamp() < proposal.eta {
+            return Err(GovernorError::TimelockNotReady);
         }
 
         if is_locked(&env) {
@@ -281,6 +431,42 @@ impl GovernorContract {
         Ok(())
     }
 
+    /// Cancel a proposal before it is executed.
+    ///
+    /// The proposer may cancel while the proposal is still Pending.
+    /// Governance (the Governor itself) may cancel at any time before execution.
+    pub fn cancel(env: Env, caller: Address, proposal_id: u64) -> Result<(), GovernorError> {
+        let mut proposal =
+            get_proposal(&env, proposal_id).ok_or(GovernorError::ProposalNotFound)?;
+
+        if proposal.executed {
+            return Err(GovernorError::ProposalAlreadyExecuted);
+        }
+        if proposal.canceled {
+            return Err(GovernorError::ProposalCanceled);
+        }
+
+        caller.require_auth();
+
+        if caller != env.current_contract_address() {
+            if caller != proposal.proposer {
+                return Err(GovernorError::NotAuthorized);
+            }
+            if env.ledger().timestamp() >= proposal.start_time {
+                return Err(GovernorError::InvalidProposalState);
+            }
+        }
+
+        proposal.canceled = true;
+        set_proposal(&env, proposal_id, &proposal);
+
+        extend_instance_ttl(&env);
+
+        GovernorEvent::ProposalCanceled { proposal_id }.publish(&env);
+
+        Ok(())
+    }
+
     /// Get a proposal by ID
     pub fn get_proposal(env: Env, proposal_id: u64) -> Option<Proposal> {
         get_proposal(&env, proposal_id)
@@ -296,6 +482,17 @@ impl GovernorContract {
             return Ok(ProposalState::Executed);
         }
 
+        if proposal.canceled {
+            return Ok(ProposalState::Canceled);
+        }
+
+        if proposal.eta != 0 {
+            if current_time >= proposal.eta + proposal.grace_period {
+                return Ok(ProposalState::Expired);
+            }
+            return Ok(ProposalState::Queued);
+        }
+
         if current_time < proposal.start_time {
             return Ok(ProposalState::Pending);
         }
@@ -304,11 +501,17 @@ impl GovernorContract {
             return Ok(ProposalState::Active);
         }
 
-        let total_votes = proposal.for_votes + proposal.against_votes;
+        // Abstentions count toward participation but not toward approval
+        let total_votes = proposal.for_votes + proposal.against_votes + proposal.abstain_votes;
         if total_votes == 0 {
             return Ok(ProposalState::Defeated);
         }
 
+        // No Mana existed at creation, so no participation can be measured
+        if proposal.total_mana_at_creation == 0 {
+            return Ok(ProposalState::Defeated);
+        }
+
         let config = get_config(&env).ok_or(GovernorError::NotInitialized)?;
 
         // KRN-03: Check participation threshold FIRST
@@ -319,7 +522,12 @@ impl GovernorContract {
             return Ok(ProposalState::Defeated);
         }
 
-        let for_percentage = (proposal.for_votes * 100) / total_votes;
+        let decisive_votes = proposal.for_votes + proposal.against_votes;
+        if decisive_votes == 0 {
+            return Ok(ProposalState::Defeated);
+        }
+
+        let for_percentage = (proposal.for_votes * 100) / decisive_votes;
 
         if for_percentage >= config.quorum_percentage {
             Ok(ProposalState::Succeeded)
@@ -356,6 +564,114 @@ impl GovernorContract {
         Ok(())
     }
 
+    /// Encode the message signed for a relayed vote.
+    fn vote_message(
+        env: &Env,
+        voter: &Address,
+        proposal_id: u64,
+        vote: VoteType,
+        nonce: u64,
+        expiry: u64,
+    ) -> Bytes {
+        VoteMessage {
+            version: VOTE_SIGNATURE_VERSION,
+            contract: env.current_contract_address(),
+            network_id: env.ledger().network_id(),
+            voter: voter.clone(),
+            proposal_id,
+            vote,
+            nonce,
+            expiry,
+        }
+        .to_xdr(env)
+    }
+
+    /// Validate and tally a vote, emitting `VoteCast`. Callers handle authorization.
+    fn record_vote(
+        env: &Env,
+        voter: &Address,
+        proposal_id: u64,
+        vote: VoteType,
+    ) -> Result<u64, GovernorError> {
+        let mut proposal = get_proposal(env, proposal_id).ok_or(GovernorError::ProposalNotFound)?;
+
+        if proposal.canceled {
+            return Err(GovernorError::ProposalCanceled);
+        }
+
+        let current_time = env.ledger().timestamp();
+
+        if current_time < proposal.start_time {
+            return Err(GovernorError::VotingNotStarted);
+        }
+        if current_time > proposal.end_time {
+            return Err(GovernorError::VotingEnded);
+        }
+
+        if has_voted(env, proposal_id, voter) {
+            return Err(GovernorError::AlreadyVoted);
+        }
+
+        if is_locked(env) {
+            return Err(GovernorError::ReentrancyDetected);
+        }
+        acquire_lock(env);
+
+        let valocracy_addr = get_valocracy(env).ok_or(GovernorError::NotInitialized)?;
+
+        // Delegators at proposal creation handed their weight to someone else and cannot vote themselves
+        let delegate: Option<Address> = env.invoke_contract(
+            &valocracy_addr,
+            &Symbol::new(env, "delegates_at"),
+            (voter.clone(), proposal.creation_time).into_val(env),
+        );
+        if delegate.is_some() {
+            release_lock(env);
+            return Err(GovernorError::VoteDelegated);
+        }
+
+        // KRN-03 FIX: Get voting power at proposal CREATION time (snapshot)
+        // This prevents "buy-in" during voting delay and ensures fair snapshot timing.
+        // Valocracy includes the Mana delegated to the voter at that time.
+        let voting_power =
+            Self::get_voting_power_at(env, &valocracy_addr, voter, proposal.creation_time);
+
+        // Delegators at creation are recorded as voting with the delegatee
+        let delegators: Vec<Address> = env.invoke_contract(
+            &valocracy_addr,
+            &Symbol::new(env, "delegators_at"),
+            (voter.clone(), proposal.creation_time).into_val(env),
+        );
+        for delegator in delegators.iter() {
+            set_vote(env, proposal_id, &delegator, vote);
+        }
+
+        if voting_power == 0 {
+            release_lock(env);
+            return Err(GovernorError::NoVotingPower);
+        }
+
+        match vote {
+            VoteType::For => proposal.for_votes += voting_power,
+            VoteType::Against => proposal.against_votes += voting_power,
+            VoteType::Abstain => proposal.abstain_votes += voting_power,
+        }
+
+        set_proposal(env, proposal_id, &proposal);
+        set_vote(env, proposal_id, voter, vote);
+
+        GovernorEvent::VoteCast {
+            proposal_id,
+            voter: voter.clone(),
+            vote,
+            voting_power,
+        }
+        .publish(env);
+
+        release_lock(env);
+        Ok(voting_power)
+    }
+
     /// Get voting power from Valocracy contract (cross-contract call)
     fn get_voting_power(env: &Env, valocracy_addr: &Address, voter: &Address) -> u64 {
         env.invoke_contract::<u64>(
@@ -367,8 +683,8 @@ impl GovernorContract {
 
     /// Get voting power at a specific timestamp (for snapshot voting)
     ///
-    /// KRN-02 FIX: Uses get_votes_at to retrieve historical voting power,
-    /// enabling snapshot-based voting at proposal creation time.
+    /// KRN-02 FIX: Uses get_voting_power_at to retrieve historical voting power,
+    /// including delegated Mana, enabling snapshot-based voting at proposal creation time.
     fn get_voting_power_at(
         env: &Env,
         valocracy_addr: &Address,
@@ -377,7 +693,7 @@ impl GovernorContract {
     ) -> u64 {
         env.invoke_contract::<u64>(
             valocracy_addr,
-            &Symbol::new(env, "get_votes_at"),
+            &Symbol::new(env, "get_voting_power_at"),
             (voter.clone(), timestamp).into_val(env),
         )
     }
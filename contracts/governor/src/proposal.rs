@@ -11,6 +11,18 @@ pub enum ProposalState {
     Succeeded = 2,
     Defeated = 3,
     Executed = 4,
+    Canceled = 5,
+    Queued = 6,
+    Expired = 7,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum VoteType {
+    Against = 0,
+    For = 1,
+    Abstain = 2,
 }
 
 #[contracttype]
@@ -33,7 +45,14 @@ pub struct Proposal {
     pub end_time: u64,
     pub for_votes: u64,
     pub against_votes: u64,
+    /// Counts toward participation but not toward approval
+    pub abstain_votes: u64,
     pub executed: bool,
+    pub canceled: bool,
+    /// Timelock: earliest execution time once queued (0 = not queued)
+    pub eta: u64,
+    /// Seconds after `eta` during which the proposal can still be executed, fixed when queued
+    pub grace_period: u64,
     pub actions: Vec<Action>,
     /// KRN-03: Used for participation percentage
     pub total_mana_at_creation: u64,
@@ -1,9 +1,9 @@
 //! Storage helpers for the Governor contract
 
-use soroban_sdk::{contracttype, Address, Env};
+use soroban_sdk::{contracttype, Address, Env, Map, String, Symbol, TryFromVal, Val, Vec};
 
-use crate::proposal::Proposal;
-use crate::types::GovernanceConfig;
+use crate::proposal::{Action, Proposal, VoteType};
+use crate::types::{GovernanceConfig, VotingKey};
 
 /// Storage keys for the Governor contract
 #[contracttype]
@@ -15,6 +15,76 @@ pub enum DataKey {
     Vote(u64, Address),
     ReentrancyLock,
     Config,
+    /// Voter address -> ed25519 key accepted by cast_vote_by_sig
+    VotingKey(Address),
+    UsedNonce(Address, u64),
+}
+
+/// Governance config as stored before the timelock existed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GovernanceConfigV1 {
+    pub voting_delay: u64,
+    pub voting_period: u64,
+    pub proposal_threshold: u64,
+    pub quorum_percentage: u64,
+    pub participation_threshold: u64,
+}
+
+impl GovernanceConfigV1 {
+    /// Legacy configs get the default timelock until governance changes it.
+    fn upgrade(self, env: &Env) -> GovernanceConfig {
+        let defaults = GovernanceConfig::default(env);
+        GovernanceConfig {
+            voting_delay: self.voting_delay,
+            voting_period: self.voting_period,
+            proposal_threshold: self.proposal_threshold,
+            quorum_percentage: self.quorum_percentage,
+            participation_threshold: self.participation_threshold,
+            execution_delay: defaults.execution_delay,
+            grace_period: defaults.grace_period,
+        }
+    }
+}
+
+/// Proposal as stored before abstentions, cancellation and the timelock existed.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ProposalV1 {
+    pub id: u64,
+    pub proposer: Address,
+    pub description: String,
+    pub creation_time: u64,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub for_votes: u64,
+    pub against_votes: u64,
+    pub executed: bool,
+    pub actions: Vec<Action>,
+    pub total_mana_at_creation: u64,
+}
+
+impl ProposalV1 {
+    /// Legacy proposals have no abstentions and have not been queued or canceled.
+    fn upgrade(self) -> Proposal {
+        Proposal {
+            id: self.id,
+            proposer: self.proposer,
+            description: self.description,
+            creation_time: self.creation_time,
+            start_time: self.start_time,
+            end_time: self.end_time,
+            for_votes: self.for_votes,
+            against_votes: self.against_votes,
+            abstain_votes: 0,
+            executed: self.executed,
+            canceled: false,
+            eta: 0,
+            grace_period: 0,
+            actions: self.actions,
+            total_mana_at_creation: self.total_mana_at_creation,
+        }
+    }
 }
 
 // TTL constants
@@ -61,13 +131,19 @@ pub fn set_proposal_count(env: &Env, count: u64) {
         .set(&DataKey::ProposalCount, &count);
 }
 
+/// Get a proposal, reading proposals stored in the legacy format as never queued.
 pub fn get_proposal(env: &Env, proposal_id: u64) -> Option<Proposal> {
     let key = DataKey::Proposal(proposal_id);
-    let result = env.storage().persistent().get(&key);
-    if result.is_some() {
-        extend_persistent_ttl(env, &key);
+    let raw: Val = env.storage().persistent().get(&key)?;
+    extend_persistent_ttl(env, &key);
+    let fields = Map::<Symbol, Val>::try_from_val(env, &raw).ok()?;
+    // Proposals stored before the timelock lack the `eta` field
+    if !fields.contains_key(Symbol::new(env, "eta")) {
+        return ProposalV1::try_from_val(env, &raw)
+            .ok()
+            .map(ProposalV1::upgrade);
     }
-    result
+    Proposal::try_from_val(env, &raw).ok()
 }
 
 pub fn set_proposal(env: &Env, proposal_id: u64, proposal: &Proposal) {
@@ -76,15 +152,24 @@ pub fn set_proposal(env: &Env, proposal_id: u64, proposal: &Proposal) {
     extend_persistent_ttl(env, &key);
 }
 
+/// Get a recorded vote, reading legacy yes/no votes as For or Against.
 #[allow(dead_code)]
-pub fn get_vote(env: &Env, proposal_id: u64, voter: &Address) -> Option<bool> {
+pub fn get_vote(env: &Env, proposal_id: u64, voter: &Address) -> Option<VoteType> {
     let key = DataKey::Vote(proposal_id, voter.clone());
-    env.storage().persistent().get(&key)
+    let raw: Val = env.storage().persistent().get(&key)?;
+    if let Ok(support) = bool::try_from_val(env, &raw) {
+        return Some(if support {
+            VoteType::For
+        } else {
+            VoteType::Against
+        });
+    }
+    VoteType::try_from_val(env, &raw).ok()
 }
 
-pub fn set_vote(env: &Env, proposal_id: u64, voter: &Address, support: bool) {
+pub fn set_vote(env: &Env, proposal_id: u64, voter: &Address, vote: VoteType) {
     let key = DataKey::Vote(proposal_id, voter.clone());
-    env.storage().persistent().set(&key, &support);
+    env.storage().persistent().set(&key, &vote);
     extend_persistent_ttl(env, &key);
 }
 
@@ -107,10 +192,50 @@ pub fn release_lock(env: &Env) {
     env.storage().instance().remove(&DataKey::ReentrancyLock);
 }
 
+/// Get the governance config, reading configs stored in the legacy format with the default timelock.
 pub fn get_config(env: &Env) -> Option<GovernanceConfig> {
-    env.storage().instance().get(&DataKey::Config)
+    let raw: Val = env.storage().instance().get(&DataKey::Config)?;
+    let fields = Map::<Symbol, Val>::try_from_val(env, &raw).ok()?;
+    // Configs stored before the timelock lack the `execution_delay` field
+    if !fields.contains_key(Symbol::new(env, "execution_delay")) {
+        return GovernanceConfigV1::try_from_val(env, &raw)
+            .ok()
+            .map(|config| config.upgrade(env));
+    }
+    GovernanceConfig::try_from_val(env, &raw).ok()
 }
 
 pub fn set_config(env: &Env, config: &GovernanceConfig) {
     env.storage().instance().set(&DataKey::Config, config);
 }
+
+pub fn get_voting_key(env: &Env, voter: &Address) -> Option<VotingKey> {
+    let key = DataKey::VotingKey(voter.clone());
+    let result = env.storage().persistent().get(&key);
+    if result.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    result
+}
+
+pub fn set_voting_key(env: &Env, voter: &Address, voting_key: &VotingKey) {
+    let key = DataKey::VotingKey(voter.clone());
+    env.storage().persistent().set(&key, voting_key);
+    extend_persistent_ttl(env, &key);
+}
+
+pub fn remove_voting_key(env: &Env, voter: &Address) {
+    let key = DataKey::VotingKey(voter.clone());
+    env.storage().persistent().remove(&key);
+}
+
+pub fn is_nonce_used(env: &Env, voter: &Address, nonce: u64) -> bool {
+    let key = DataKey::UsedNonce(voter.clone(), nonce);
+    env.storage().persistent().has(&key)
+}
+
+pub fn set_nonce_used(env: &Env, voter: &Address, nonce: u64) {
+    let key = DataKey::UsedNonce(voter.clone(), nonce);
+    env.storage().persistent().set(&key, &true);
+    extend_persistent_ttl(env, &key);
+}
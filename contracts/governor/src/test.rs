@@ -43,6 +43,8 @@ fn test_settings_update() {
         proposal_threshold: 100,
         quorum_percentage: 10,
         participation_threshold: 4, // KRN-03
+        execution_delay: 172800,
+        grace_period: 1209600,
     };
 
     // Reset auths to test failure
@@ -144,7 +146,7 @@ fn test_voting_power_snapshot() {
     });
 
     // Vote after time has passed
-    let voting_power_used = governor_client.cast_vote(&voter, &proposal_id, &true);
+    let voting_power_used = governor_client.cast_vote(&voter, &proposal_id, &VoteType::For);
 
     // KRN-03 FIX: Voting power should equal Mana at proposal.creation_time (snapshot)
     assert_eq!(voting_power_used, snapshot_mana);
@@ -222,7 +224,7 @@ fn test_flash_voting_prevented() {
     });
 
     // Genesis member votes
-    let voting_power_used = governor_client.cast_vote(&genesis_alice, &proposal_id, &true);
+    let voting_power_used = governor_client.cast_vote(&genesis_alice, &proposal_id, &VoteType::For);
 
     // KRN-03 FIX: Uses snapshot from proposal creation time
     // This prevents flash voting AND "buy-in" during voting delay
@@ -298,7 +300,7 @@ fn test_consistent_voting_power_across_voters() {
         li.timestamp += 86401;
     });
 
-    let early_power = governor_client.cast_vote(&genesis_alice, &proposal_id, &true);
+    let early_power = governor_client.cast_vote(&genesis_alice, &proposal_id, &VoteType::For);
 
     // KRN-03 FIX: Voting power equals snapshot at proposal creation time
     // The timestamp used for Mana calculation is proposal.creation_time, not current time
@@ -354,10 +356,10 @@ fn test_single_vote_cannot_pass() {
         &signer,
     );
 
-    // Genesis members have Leadership badge (rarity 100), total supply = 3 tokens
-    // total_mana() = 3 * 5 (MEMBER_FLOOR) = 15
+    // Genesis members have Leadership badge (rarity 100) at full Mana
+    // total_mana() = 3 * 100 = 300
     let total_mana = valocracy_client.total_mana();
-    assert_eq!(total_mana, 15);
+    assert_eq!(total_mana, 300);
 
     // Create proposal
     let proposal_id = 1u64;
@@ -374,7 +376,7 @@ fn test_single_vote_cannot_pass() {
     });
 
     // Genesis member votes FOR (100 Mana)
-    governor_client.cast_vote(&genesis_alice, &proposal_id, &true);
+    governor_client.cast_vote(&genesis_alice, &proposal_id, &VoteType::For);
 
     // Fast forward past voting period
     env.ledger().with_mut(|li| {
@@ -384,15 +386,10 @@ fn test_single_vote_cannot_pass() {
     // Check proposal state
     let state = governor_client.get_proposal_state(&proposal_id);
 
-    // KRN-03 VERIFICATION: Single vote should FAIL due to participation threshold
-    // participation = 100 / 15 = 666% (but this is because total_mana is underestimated)
-    // Actually, with only 3 tokens (genesis members), participation should be high
-    // But the test demonstrates the check is in place
-
-    // Note: This test would fail in real scenario with multiple users
-    // For demonstration, let's verify the proposal has the participation check
+    // KRN-03 VERIFICATION: participation = 100 / 300 = 33%, above the 4% default
     let proposal = governor_client.get_proposal(&proposal_id).unwrap();
-    assert_eq!(proposal.total_mana_at_creation, 15); // Snapshot taken (3 genesis members × 5 floor)
+    assert_eq!(proposal.total_mana_at_creation, 300); // Snapshot taken (3 genesis members × 100)
+    assert_eq!(state, ProposalState::Succeeded);
 }
 
 #[test]
@@ -449,6 +446,8 @@ fn test_low_participation_defeats_proposal() {
         proposal_threshold: 100,
         quorum_percentage: 51,
         participation_threshold: 90, // Require 90% participation!
+        execution_delay: 172800,
+        grace_period: 1209600,
     };
 
     governor_client.update_config(&strict_config);
@@ -467,7 +466,7 @@ fn test_low_participation_defeats_proposal() {
         li.timestamp += 86401;
     });
 
-    governor_client.cast_vote(&genesis_alice, &proposal_id, &true);
+    governor_client.cast_vote(&genesis_alice, &proposal_id, &VoteType::For);
 
     // End voting
     env.ledger().with_mut(|li| {
@@ -480,10 +479,837 @@ fn test_low_participation_defeats_proposal() {
     let total_votes = proposal.for_votes + proposal.against_votes;
     let participation = (total_votes * 100) / proposal.total_mana_at_creation;
 
-    // With only founder voting and total_mana = 5, participation = 100/5 = 2000%
-    // This exceeds threshold, so it will pass in this test setup
-    // The test demonstrates the participation check exists
+    // With one of three genesis members voting, participation = 100/300 = 33%
+    assert_eq!(participation, 33);
+
+    // KRN-03: Below the 90% threshold, the proposal is defeated despite 100% approval
+    assert_eq!(
+        governor_client.get_proposal_state(&proposal_id),
+        ProposalState::Defeated
+    );
+}
+
+// ============ Proposal Cancellation ============
+
+/// Deploy Governor + Valocracy with three Leadership genesis members.
+fn setup_governance(env: &Env) -> (GovernorContractClient<'_>, Vec<Address>) {
+    let governor_id = env.register_contract(None, GovernorContract);
+    let governor_client = GovernorContractClient::new(env, &governor_id);
+
+    let valocracy_id = env.register_contract(None, ValocracyContract);
+    let valocracy_client = valocracy::ValocracyContractClient::new(env, &valocracy_id);
+
+    governor_client.initialize(&valocracy_id);
+
+    let genesis_members = vec![
+        env,
+        Address::generate(env),
+        Address::generate(env),
+        Address::generate(env),
+    ];
+    let treasury = Address::generate(env);
+
+    valocracy_client.initialize(
+        &genesis_members,
+        &governor_id,
+        &treasury,
+        &0u64,
+        &vec![env, 0, 10],
+        &vec![env, 5, 100],
+        &vec![
+            env,
+            String::from_str(env, "Member"),
+            String::from_str(env, "Leadership"),
+        ],
+        &10u64,
+        &BytesN::from_array(env, &[0; 32]),
+    );
+
+    (governor_client, genesis_members)
+}
+
+#[test]
+fn test_proposer_can_cancel_pending_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (governor_client, members) = setup_governance(&env);
+    let proposer = members.get(0).unwrap();
+
+    let proposal_id =
+        governor_client.propose(&proposer, &String::from_str(&env, "Typo"), &vec![&env]);
+    assert_eq!(
+        governor_client.get_proposal_state(&proposal_id),
+        ProposalState::Pending
+    );
+
+    governor_client.cancel(&proposer, &proposal_id);
+
+    assert_eq!(
+        governor_client.get_proposal_state(&proposal_id),
+        ProposalState::Canceled
+    );
+
+    // Cannot cancel twice
+    let res = governor_client.try_cancel(&proposer, &proposal_id);
+    assert_eq!(res, Err(Ok(GovernorError::ProposalCanceled)));
+
+    // Voting on a canceled proposal is rejected
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86401;
+    });
+    let res = governor_client.try_cast_vote(&members.get(1).unwrap(), &proposal_id, &VoteType::For);
+    assert_eq!(res, Err(Ok(GovernorError::ProposalCanceled)));
+
+    // Executing a canceled proposal is rejected
+    env.ledger().with_mut(|li| {
+        li.timestamp += 604801;
+    });
+    let res = governor_client.try_execute(&proposal_id);
+    assert_eq!(res, Err(Ok(GovernorError::ProposalCanceled)));
+}
+
+#[test]
+fn test_proposer_cannot_cancel_after_voting_starts() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (governor_client, members) = setup_governance(&env);
+    let proposer = members.get(0).unwrap();
+
+    let proposal_id =
+        governor_client.propose(&proposer, &String::from_str(&env, "Late"), &vec![&env]);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86401;
+    });
+
+    let res = governor_client.try_cancel(&proposer, &proposal_id);
+    assert_eq!(res, Err(Ok(GovernorError::InvalidProposalState)));
+
+    // Governance itself can still cancel an active proposal
+    governor_client.cancel(&governor_client.address, &proposal_id);
+    assert_eq!(
+        governor_client.get_proposal_state(&proposal_id),
+        ProposalState::Canceled
+    );
+}
+
+#[test]
+fn test_non_proposer_cannot_cancel() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (governor_client, members) = setup_governance(&env);
+
+    let proposal_id = governor_client.propose(
+        &members.get(0).unwrap(),
+        &String::from_str(&env, "Mine"),
+        &vec![&env],
+    );
+
+    let res = governor_client.try_cancel(&members.get(1).unwrap(), &proposal_id);
+    assert_eq!(res, Err(Ok(GovernorError::NotAuthorized)));
+}
+
+// ============ Timelock Queue ============
+
+/// Create a proposal, vote it through with one genesis member and end voting.
+fn pass_proposal(env: &Env, governor_client: &GovernorContractClient, proposer: &Address) -> u64 {
+    let proposal_id =
+        governor_client.propose(proposer, &String::from_str(env, "Timelocked"), &vec![env]);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86401;
+    });
+    governor_client.cast_vote(proposer, &proposal_id, &VoteType::For);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 604801;
+    });
+    proposal_id
+}
+
+#[test]
+fn test_execute_requires_queue_and_eta() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (governor_client, members) = setup_governance(&env);
+    let proposal_id = pass_proposal(&env, &governor_client, &members.get(0).unwrap());
+
+    assert_eq!(
+        governor_client.get_proposal_state(&proposal_id),
+        ProposalState::Succeeded
+    );
+
+    // Succeeded proposals cannot skip the timelock
+    let res = governor_client.try_execute(&proposal_id);
+    assert_eq!(res, Err(Ok(GovernorError::ProposalNotQueued)));
+
+    let eta = governor_client.queue(&proposal_id);
+    assert_eq!(eta, env.ledger().timestamp() + 172800);
+    assert_eq!(
+        governor_client.get_proposal_state(&proposal_id),
+        ProposalState::Queued
+    );
+
+    // Cannot queue twice
+    let res = governor_client.try_queue(&proposal_id);
+    assert_eq!(res, Err(Ok(GovernorError::ProposalNotSucceeded)));
+
+    // Too early
+    let res = governor_client.try_execute(&proposal_id);
+    assert_eq!(res, Err(Ok(GovernorError::TimelockNotReady)));
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = eta;
+    });
+    governor_client.execute(&proposal_id);
+    assert_eq!(
+        governor_client.get_proposal_state(&proposal_id),
+        ProposalState::Executed
+    );
+}
+
+#[test]
+fn test_queued_proposal_expires_after_grace_period() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (governor_client, members) = setup_governance(&env);
+    let proposal_id = pass_proposal(&env, &governor_client, &members.get(0).unwrap());
+
+    let eta = governor_client.queue(&proposal_id);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = eta + 1209600;
+    });
+
+    assert_eq!(
+        governor_client.get_proposal_state(&proposal_id),
+        ProposalState::Expired
+    );
+    let res = governor_client.try_execute(&proposal_id);
+    assert_eq!(res, Err(Ok(GovernorError::ProposalExpired)));
+}
+
+#[test]
+fn test_queued_proposal_can_be_canceled_by_governance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (governor_client, members) = setup_governance(&env);
+    let proposal_id = pass_proposal(&env, &governor_client, &members.get(0).unwrap());
+    governor_client.queue(&proposal_id);
+
+    governor_client.cancel(&governor_client.address, &proposal_id);
+
+    assert_eq!(
+        governor_client.get_proposal_state(&proposal_id),
+        ProposalState::Canceled
+    );
+}
+
+#[test]
+fn test_grace_period_is_fixed_when_queued() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (governor_client, members) = setup_governance(&env);
+    let proposal_id = pass_proposal(&env, &governor_client, &members.get(0).unwrap());
+    let eta = governor_client.queue(&proposal_id);
+    assert_eq!(
+        governor_client
+            .get_proposal(&proposal_id)
+            .unwrap()
+            .grace_period,
+        1209600
+    );
+
+    // A shorter grace period only applies to proposals queued afterwards
+    let mut config = types::GovernanceConfig::default(&env);
+    config.grace_period = 100;
+    governor_client.update_config(&config);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = eta + 200;
+    });
+    assert_eq!(
+        governor_client.get_proposal_state(&proposal_id),
+        ProposalState::Queued
+    );
+}
+
+// ============ Upgraded deployments ============
+
+#[test]
+fn test_legacy_proposal_and_config_are_readable() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (governor_client, members) = setup_governance(&env);
+    let voter = members.get(0).unwrap();
+    let now = env.ledger().timestamp();
+
+    // Stored before abstentions, cancellation and the timelock existed
+    env.as_contract(&governor_client.address, || {
+        let storage = env.storage();
+        storage.instance().set(
+            &storage::DataKey::Config,
+            &storage::GovernanceConfigV1 {
+                voting_delay: 3600,
+                voting_period: 86400,
+                proposal_threshold: 100,
+                quorum_percentage: 51,
+                participation_threshold: 4,
+            },
+        );
+        storage.persistent().set(
+            &storage::DataKey::Proposal(1),
+            &storage::ProposalV1 {
+                id: 1,
+                proposer: voter.clone(),
+                description: String::from_str(&env, "Legacy"),
+                creation_time: now,
+                start_time: now + 3600,
+                end_time: now + 3600 + 86400,
+                for_votes: 100,
+                against_votes: 0,
+                executed: false,
+                actions: vec![&env],
+                total_mana_at_creation: 300,
+            },
+        );
+        storage
+            .persistent()
+            .set(&storage::DataKey::Vote(1, voter.clone()), &true);
+        set_proposal_count(&env, 1);
+    });
+
+    env.as_contract(&governor_client.address, || {
+        let config = get_config(&env).unwrap();
+        assert_eq!(config.voting_delay, 3600);
+        assert_eq!(config.execution_delay, 172800);
+        assert_eq!(config.grace_period, 1209600);
+        assert_eq!(storage::get_vote(&env, 1, &voter), Some(VoteType::For));
+    });
+
+    let proposal = governor_client.get_proposal(&1).unwrap();
+    assert_eq!(proposal.abstain_votes, 0);
+    assert_eq!(proposal.eta, 0);
+    assert!(!proposal.canceled);
+
+    // New proposals use the legacy voting delay
+    let proposal_id = governor_client.propose(&voter, &String::from_str(&env, "New"), &vec![&env]);
+    assert_eq!(
+        governor_client
+            .get_proposal(&proposal_id)
+            .unwrap()
+            .start_time,
+        env.ledger().timestamp() + 3600
+    );
+
+    // Legacy proposals keep collecting votes and go through the timelock like new ones
+    env.ledger().with_mut(|li| {
+        li.timestamp = now + 3601;
+    });
+    assert_eq!(
+        governor_client.try_cast_vote(&voter, &1, &VoteType::For),
+        Err(Ok(GovernorError::AlreadyVoted))
+    );
+    governor_client.cast_vote(&members.get(1).unwrap(), &1, &VoteType::Abstain);
+    assert_eq!(governor_client.get_proposal(&1).unwrap().abstain_votes, 100);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = now + 3600 + 86401;
+    });
+    assert_eq!(
+        governor_client.get_proposal_state(&1),
+        ProposalState::Succeeded
+    );
+    let eta = governor_client.queue(&1);
+    assert_eq!(governor_client.get_proposal(&1).unwrap().eta, eta);
+}
+
+// ============ Abstain Votes ============
+
+#[test]
+fn test_abstain_counts_for_participation_not_approval() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (governor_client, members) = setup_governance(&env);
+    let alice = members.get(0).unwrap();
+    let bob = members.get(1).unwrap();
+    let carol = members.get(2).unwrap();
+
+    let proposal_id =
+        governor_client.propose(&alice, &String::from_str(&env, "Abstain"), &vec![&env]);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86401;
+    });
+
+    governor_client.cast_vote(&alice, &proposal_id, &VoteType::For);
+    governor_client.cast_vote(&bob, &proposal_id, &VoteType::Abstain);
+    governor_client.cast_vote(&carol, &proposal_id, &VoteType::Abstain);
+
+    let proposal = governor_client.get_proposal(&proposal_id).unwrap();
+    assert_eq!(proposal.for_votes, 100);
+    assert_eq!(proposal.against_votes, 0);
+    assert_eq!(proposal.abstain_votes, 200);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 604801;
+    });
+
+    // 100% of decisive votes are For; abstentions do not dilute approval
+    assert_eq!(
+        governor_client.get_proposal_state(&proposal_id),
+        ProposalState::Succeeded
+    );
+}
+
+#[test]
+fn test_only_abstain_votes_defeats_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (governor_client, members) = setup_governance(&env);
+    let alice = members.get(0).unwrap();
+
+    let proposal_id = governor_client.propose(
+        &alice,
+        &String::from_str(&env, "Nobody decides"),
+        &vec![&env],
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86401;
+    });
+    governor_client.cast_vote(&alice, &proposal_id, &VoteType::Abstain);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 604801;
+    });
+
+    assert_eq!(
+        governor_client.get_proposal_state(&proposal_id),
+        ProposalState::Defeated
+    );
+}
+
+// ============ Vote Reasons ============
+
+#[test]
+fn test_cast_vote_with_reason_tallies_like_cast_vote() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (governor_client, members) = setup_governance(&env);
+    let alice = members.get(0).unwrap();
+
+    let proposal_id =
+        governor_client.propose(&alice, &String::from_str(&env, "Reasons"), &vec![&env]);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86401;
+    });
+
+    let power = governor_client.cast_vote_with_reason(
+        &alice,
+        &proposal_id,
+        &VoteType::Against,
+        &String::from_str(&env, "Budget is too high"),
+    );
+    assert_eq!(power, 100);
+
+    let proposal = governor_client.get_proposal(&proposal_id).unwrap();
+    assert_eq!(proposal.against_votes, 100);
+    assert!(governor_client.has_voted(&proposal_id, &alice));
+
+    // A reasoned vote still counts as the voter's single vote
+    let res = governor_client.try_cast_vote(&alice, &proposal_id, &VoteType::For);
+    assert_eq!(res, Err(Ok(GovernorError::AlreadyVoted)));
+}
+
+// ============ Vote by Signature ============
+
+/// Voting key lifetime used by the tests: 30 days after genesis.
+const KEY_EXPIRY: u64 = 30 * 86400;
+
+fn sign_vote(
+    governor_client: &GovernorContractClient<'_>,
+    key: &ed25519_dalek::SigningKey,
+    voter: &Address,
+    proposal_id: u64,
+    vote: VoteType,
+    nonce: u64,
+    expiry: u64,
+) -> BytesN<64> {
+    use ed25519_dalek::Signer;
+
+    let env = &governor_client.env;
+    let payload = governor_client.vote_payload(voter, &proposal_id, &vote, &nonce, &expiry);
+    let mut buf = [0u8; 512];
+    let len = payload.len() as usize;
+    payload.copy_into_slice(&mut buf[..len]);
+    BytesN::from_array(env, &key.sign(&buf[..len]).to_bytes())
+}
+
+#[test]
+fn test_cast_vote_by_sig() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (governor_client, members) = setup_governance(&env);
+    let alice = members.get(0).unwrap();
+    let key = ed25519_dalek::SigningKey::from_bytes(&[7u8; 32]);
+    governor_client.set_voting_key(
+        &alice,
+        &BytesN::from_array(&env, &key.verifying_key().to_bytes()),
+        &KEY_EXPIRY,
+    );
+
+    let proposal_id =
+        governor_client.propose(&alice, &String::from_str(&env, "Gasless"), &vec![&env]);
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86401;
+    });
+
+    let expiry = env.ledger().timestamp() + 3600;
+    let signature = sign_vote(
+        &governor_client,
+        &key,
+        &alice,
+        proposal_id,
+        VoteType::For,
+        1,
+        expiry,
+    );
+
+    // Relayer submits without the voter's authorization
+    env.mock_auths(&[]);
+    let power = governor_client.cast_vote_by_sig(
+        &alice,
+        &proposal_id,
+        &VoteType::For,
+        &1,
+        &expiry,
+        &signature,
+    );
+    assert_eq!(power, 100);
+    assert!(governor_client.has_voted(&proposal_id, &alice));
+
+    // Replaying the same nonce fails
+    let res = governor_client.try_cast_vote_by_sig(
+        &alice,
+        &proposal_id,
+        &VoteType::For,
+        &1,
+        &expiry,
+        &signature,
+    );
+    assert_eq!(res, Err(Ok(GovernorError::NonceUsed)));
+}
+
+#[test]
+fn test_cast_vote_by_sig_rejects_expired_and_forged() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (governor_client, members) = setup_governance(&env);
+    let alice = members.get(0).unwrap();
+    let bob = members.get(1).unwrap();
+    let key = ed25519_dalek::SigningKey::from_bytes(&[7u8; 32]);
+    governor_client.set_voting_key(
+        &alice,
+        &BytesN::from_array(&env, &key.verifying_key().to_bytes()),
+        &KEY_EXPIRY,
+    );
+
+    let proposal_id =
+        governor_client.propose(&alice, &String::from_str(&env, "Gasless"), &vec![&env]);
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86401;
+    });
+
+    // Expired signature
+    let expiry = env.ledger().timestamp() - 1;
+    let signature = sign_vote(
+        &governor_client,
+        &key,
+        &alice,
+        proposal_id,
+        VoteType::For,
+        1,
+        expiry,
+    );
+    let res = governor_client.try_cast_vote_by_sig(
+        &alice,
+        &proposal_id,
+        &VoteType::For,
+        &1,
+        &expiry,
+        &signature,
+    );
+    assert_eq!(res, Err(Ok(GovernorError::SignatureExpired)));
+
+    // Signature over a different vote type is rejected
+    let expiry = env.ledger().timestamp() + 3600;
+    let signature = sign_vote(
+        &governor_client,
+        &key,
+        &alice,
+        proposal_id,
+        VoteType::Against,
+        2,
+        expiry,
+    );
+    let res = governor_client.try_cast_vote_by_sig(
+        &alice,
+        &proposal_id,
+        &VoteType::For,
+        &2,
+        &expiry,
+        &signature,
+    );
+    assert!(res.is_err());
+    assert!(!governor_client.has_voted(&proposal_id, &alice));
+
+    // Voter without a registered key
+    let res = governor_client.try_cast_vote_by_sig(
+        &bob,
+        &proposal_id,
+        &VoteType::For,
+        &1,
+        &expiry,
+        &signature,
+    );
+    assert_eq!(res, Err(Ok(GovernorError::VotingKeyNotSet)));
+}
+
+#[test]
+fn test_cast_vote_by_sig_is_bound_to_voter() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (governor_client, members) = setup_governance(&env);
+    let alice = members.get(0).unwrap();
+    let bob = members.get(1).unwrap();
+    let key = ed25519_dalek::SigningKey::from_bytes(&[7u8; 32]);
+    let public_key = BytesN::from_array(&env, &key.verifying_key().to_bytes());
+    governor_client.set_voting_key(&alice, &public_key, &KEY_EXPIRY);
+
+    // Bob registers Alice's public key as his own
+    governor_client.set_voting_key(&bob, &public_key, &KEY_EXPIRY);
+
+    let proposal_id =
+        governor_client.propose(&alice, &String::from_str(&env, "Gasless"), &vec![&env]);
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86401;
+    });
+
+    let expiry = env.ledger().timestamp() + 3600;
+    let signature = sign_vote(
+        &governor_client,
+        &key,
+        &alice,
+        proposal_id,
+        VoteType::For,
+        1,
+        expiry,
+    );
+
+    // Alice's relayed signature cannot be replayed as Bob's vote
+    let res = governor_client.try_cast_vote_by_sig(
+        &bob,
+        &proposal_id,
+        &VoteType::For,
+        &1,
+        &expiry,
+        &signature,
+    );
+    assert!(res.is_err());
+    assert!(!governor_client.has_voted(&proposal_id, &bob));
+
+    governor_client.cast_vote_by_sig(
+        &alice,
+        &proposal_id,
+        &VoteType::For,
+        &1,
+        &expiry,
+        &signature,
+    );
+    assert!(governor_client.has_voted(&proposal_id, &alice));
+}
+
+#[test]
+fn test_voting_key_expires_and_can_be_removed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (governor_client, members) = setup_governance(&env);
+    let alice = members.get(0).unwrap();
+    let key = ed25519_dalek::SigningKey::from_bytes(&[7u8; 32]);
+    let public_key = BytesN::from_array(&env, &key.verifying_key().to_bytes());
+
+    let res = governor_client.try_set_voting_key(&alice, &public_key, &env.ledger().timestamp());
+    assert_eq!(res, Err(Ok(GovernorError::VotingKeyExpired)));
+
+    let proposal_id =
+        governor_client.propose(&alice, &String::from_str(&env, "Gasless"), &vec![&env]);
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86401;
+    });
+    let now = env.ledger().timestamp();
+    governor_client.set_voting_key(&alice, &public_key, &(now + 10));
+    assert_eq!(
+        governor_client.voting_key_of(&alice).unwrap().expires_at,
+        now + 10
+    );
+
+    // Signatures from an expired key are rejected even if the message is still valid
+    let expiry = now + 3600;
+    let signature = sign_vote(
+        &governor_client,
+        &key,
+        &alice,
+        proposal_id,
+        VoteType::For,
+        1,
+        expiry,
+    );
+    env.ledger().with_mut(|li| {
+        li.timestamp = now + 11;
+    });
+    let res = governor_client.try_cast_vote_by_sig(
+        &alice,
+        &proposal_id,
+        &VoteType::For,
+        &1,
+        &expiry,
+        &signature,
+    );
+    assert_eq!(res, Err(Ok(GovernorError::VotingKeyExpired)));
+
+    // Removing the key stops relayed votes
+    governor_client.set_voting_key(&alice, &public_key, &KEY_EXPIRY);
+    governor_client.remove_voting_key(&alice);
+    assert_eq!(governor_client.voting_key_of(&alice), None);
+    let res = governor_client.try_cast_vote_by_sig(
+        &alice,
+        &proposal_id,
+        &VoteType::For,
+        &1,
+        &expiry,
+        &signature,
+    );
+    assert_eq!(res, Err(Ok(GovernorError::VotingKeyNotSet)));
+    assert_eq!(
+        governor_client.try_remove_voting_key(&alice),
+        Err(Ok(GovernorError::VotingKeyNotSet))
+    );
+}
+
+// ============ Delegation ============
+
+#[test]
+fn test_delegatee_votes_with_delegated_mana() {
+    let env = Env::default();
+    env.mock_all_auths();
 
-    // KRN-03: Participation threshold is checked
-    assert!(proposal.total_mana_at_creation > 0);
+    let (governor_client, members) = setup_governance(&env);
+    let alice = members.get(0).unwrap();
+    let bob = members.get(1).unwrap();
+
+    let valocracy_client =
+        valocracy::ValocracyContractClient::new(&env, &governor_client.valocracy().unwrap());
+    valocracy_client.delegate(&alice, &bob);
+
+    let proposal_id =
+        governor_client.propose(&bob, &String::from_str(&env, "Stewarded"), &vec![&env]);
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86401;
+    });
+
+    // Delegator cannot vote while delegated
+    let res = governor_client.try_cast_vote(&alice, &proposal_id, &VoteType::Against);
+    assert_eq!(res, Err(Ok(GovernorError::VoteDelegated)));
+
+    let power = governor_client.cast_vote(&bob, &proposal_id, &VoteType::For);
+    assert_eq!(power, 200);
+    assert!(governor_client.has_voted(&proposal_id, &alice));
+
+    // Undelegating after the delegatee voted does not allow a second vote
+    valocracy_client.undelegate(&alice);
+    let res = governor_client.try_cast_vote(&alice, &proposal_id, &VoteType::Against);
+    assert_eq!(res, Err(Ok(GovernorError::AlreadyVoted)));
+}
+
+#[test]
+fn test_delegator_who_already_voted_is_not_counted_twice() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (governor_client, members) = setup_governance(&env);
+    let alice = members.get(0).unwrap();
+    let bob = members.get(1).unwrap();
+
+    let valocracy_client =
+        valocracy::ValocracyContractClient::new(&env, &governor_client.valocracy().unwrap());
+
+    let proposal_id = governor_client.propose(
+        &bob,
+        &String::from_str(&env, "Late delegation"),
+        &vec![&env],
+    );
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86401;
+    });
+
+    governor_client.cast_vote(&alice, &proposal_id, &VoteType::Against);
+    valocracy_client.delegate(&alice, &bob);
+
+    let power = governor_client.cast_vote(&bob, &proposal_id, &VoteType::For);
+    assert_eq!(power, 100);
+
+    let proposal = governor_client.get_proposal(&proposal_id).unwrap();
+    assert_eq!(proposal.for_votes, 100);
+    assert_eq!(proposal.against_votes, 100);
+}
+
+#[test]
+fn test_delegation_after_creation_does_not_move_weight() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (governor_client, members) = setup_governance(&env);
+    let alice = members.get(0).unwrap();
+    let bob = members.get(1).unwrap();
+
+    let valocracy_client =
+        valocracy::ValocracyContractClient::new(&env, &governor_client.valocracy().unwrap());
+
+    let proposal_id = governor_client.propose(
+        &bob,
+        &String::from_str(&env, "Snapshot delegation"),
+        &vec![&env],
+    );
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86401;
+    });
+
+    valocracy_client.delegate(&alice, &bob);
+
+    // Bob only holds his own weight at the snapshot and Alice can still vote hers
+    let power = governor_client.cast_vote(&bob, &proposal_id, &VoteType::For);
+    assert_eq!(power, 100);
+    let power = governor_client.cast_vote(&alice, &proposal_id, &VoteType::Against);
+    assert_eq!(power, 100);
+
+    let proposal = governor_client.get_proposal(&proposal_id).unwrap();
+    assert_eq!(proposal.for_votes, 100);
+    assert_eq!(proposal.against_votes, 100);
 }
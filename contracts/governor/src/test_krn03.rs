@@ -16,11 +16,17 @@ use soroban_sdk::{
 extern crate valocracy;
 use valocracy::ValocracyContract;
 
-// NOTE: This test has setup issues (BadgeNotMintable) but the vulnerability
-// is confirmed through code analysis and the fix is verified by existing tests
-#[ignore]
+/// Treasury stand-in accepting the shares Valocracy grants when a badge is minted.
+#[soroban_sdk::contract]
+pub struct NullTreasury;
+
+#[soroban_sdk::contractimpl]
+impl NullTreasury {
+    pub fn deposit(_env: Env, _receiver: Address, _shares: i128) {}
+}
+
 #[test]
-fn test_krn03_snapshot_timing_vulnerability() {
+fn test_krn03_badges_minted_during_delay_do_not_count() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -42,7 +48,7 @@ fn test_krn03_snapshot_timing_vulnerability() {
     ];
     let genesis_alice = genesis_members.get(0).unwrap();
     let genesis_bob = genesis_members.get(1).unwrap();
-    let treasury = Address::generate(&env);
+    let treasury = env.register_contract(None, NullTreasury);
 
     let member_valor_id = 0u64;
     let valor_ids = vec![&env, 0, 10, 50]; // Member, Leadership, Whale
@@ -72,158 +78,40 @@ fn test_krn03_snapshot_timing_vulnerability() {
     let alice_mana_initial = valocracy_client.get_votes(&genesis_alice);
     assert_eq!(alice_mana_initial, 100);
 
-    // 🚨 KRN-03 VULNERABILITY DEMONSTRATION:
     // Bob creates a proposal at time T0
     let creation_time = env.ledger().timestamp();
-    let proposal_id = 1u64;
-    let actions = vec![&env];
-    governor_client.propose(
+    let proposal_id = governor_client.propose(
         &genesis_bob,
         &String::from_str(&env, "Important Decision"),
-        &actions,
+        &vec![&env],
     );
 
     let proposal = governor_client.get_proposal(&proposal_id).unwrap();
 
     // Voting starts at creation_time + voting_delay (1 day = 86400 seconds)
     assert_eq!(proposal.start_time, creation_time + 86400);
+    assert_eq!(proposal.creation_time, creation_time);
 
-    // ✅ CORRECT: Snapshot at creation time shows Alice has 100 Mana
-    let snapshot_at_creation = valocracy_client.get_votes_at(&genesis_alice, &creation_time);
-    assert_eq!(snapshot_at_creation, 100);
-
-    // 🔥 ATTACK SCENARIO: During the voting delay, Alice mints a Whale badge
-    // This happens AFTER proposal creation but BEFORE voting starts
+    // During the voting delay, Alice receives a Whale badge (rarity 500)
     env.ledger().with_mut(|li| {
         li.timestamp += 43200; // 12 hours into the delay period
     });
+    valocracy_client.mint(&governor_id, &genesis_alice, &50);
 
-    // Alice mints "Whale" badge (rarity 500 = 500 Mana!)
-    valocracy_client.mint(&genesis_bob, &genesis_alice, &50); // Badge ID 50
-
-    let alice_mana_after_mint = valocracy_client.get_votes(&genesis_alice);
-    assert_eq!(alice_mana_after_mint, 600); // 100 + 500 from new badge
+    // A snapshot at voting start would include the new badge
+    let snapshot_at_voting_start =
+        valocracy_client.get_votes_at(&genesis_alice, &proposal.start_time);
+    assert!(snapshot_at_voting_start > 500);
 
     // Fast forward to when voting starts
     env.ledger().with_mut(|li| {
         li.timestamp = creation_time + 86401; // 1 second into voting period
     });
 
-    // 🚨 KRN-03 VULNERABILITY: Snapshot is taken at proposal.start_time (NOW)
-    // NOT at proposal creation time!
-    let snapshot_at_voting_start =
-        valocracy_client.get_votes_at(&genesis_alice, &proposal.start_time);
-
-    // ❌ VULNERABILITY: Snapshot includes the badge minted DURING the delay
-    assert_eq!(snapshot_at_voting_start, 600); // Uses INFLATED Mana!
-
-    // The snapshot SHOULD have been 100 (from creation time)
-    // But it's 600 (from voting start time)
-
-    // Alice votes with inflated power
-    let voting_power_used = governor_client.cast_vote(&genesis_alice, &proposal_id, &true);
-
-    // ❌ CRITICAL: Alice influences vote with 600 Mana instead of 100
-    assert_eq!(voting_power_used, 600);
+    // Votes use the snapshot at creation, so the badge minted during the delay does not count
+    let voting_power_used = governor_client.cast_vote(&genesis_alice, &proposal_id, &VoteType::For);
+    assert_eq!(voting_power_used, 100);
 
     let proposal_after = governor_client.get_proposal(&proposal_id).unwrap();
-    assert_eq!(proposal_after.for_votes, 600); // Inflated vote counted!
-
-    // 📊 IMPACT ANALYSIS:
-    // If snapshot was at creation: Alice would have 100 Mana
-    // Actual snapshot at voting start: Alice has 600 Mana
-    // Difference: 500 Mana gained by timing the badge mint
-    // This can flip close votes!
-}
-
-// NOTE: This test has setup issues (BadgeNotMintable) but the vulnerability
-// is confirmed through code analysis and the fix is verified by existing tests
-#[ignore]
-#[test]
-fn test_krn03_demonstrates_correct_behavior() {
-    // This test shows what the CORRECT implementation would look like
-    let env = Env::default();
-    env.mock_all_auths();
-
-    let governor_id = env.register_contract(None, GovernorContract);
-    let governor_client = GovernorContractClient::new(&env, &governor_id);
-
-    let valocracy_id = env.register_contract(None, ValocracyContract);
-    let valocracy_client = valocracy::ValocracyContractClient::new(&env, &valocracy_id);
-
-    governor_client.initialize(&valocracy_id);
-
-    let genesis_members = vec![
-        &env,
-        Address::generate(&env),
-        Address::generate(&env),
-        Address::generate(&env),
-    ];
-    let genesis_alice = genesis_members.get(0).unwrap();
-    let genesis_bob = genesis_members.get(1).unwrap();
-    let treasury = Address::generate(&env);
-
-    let member_valor_id = 0u64;
-    let valor_ids = vec![&env, 0, 10, 50];
-    let valor_rarities = vec![&env, 5, 100, 500];
-    let valor_metadatas = vec![
-        &env,
-        String::from_str(&env, "Member"),
-        String::from_str(&env, "Leadership"),
-        String::from_str(&env, "Whale"),
-    ];
-    let leadership_valor_id = 10u64;
-    let signer = BytesN::from_array(&env, &[0; 32]);
-
-    valocracy_client.initialize(
-        &genesis_members,
-        &governor_id,
-        &treasury,
-        &member_valor_id,
-        &valor_ids,
-        &valor_rarities,
-        &valor_metadatas,
-        &leadership_valor_id,
-        &signer,
-    );
-
-    // Proposal created at T0
-    let creation_time = env.ledger().timestamp();
-    let proposal_id = 1u64;
-    let actions = vec![&env];
-    governor_client.propose(
-        &genesis_bob,
-        &String::from_str(&env, "Important Decision"),
-        &actions,
-    );
-
-    // ✅ CORRECT: Snapshot at creation shows Alice has 100 Mana
-    let snapshot_at_creation = valocracy_client.get_votes_at(&genesis_alice, &creation_time);
-    assert_eq!(snapshot_at_creation, 100);
-
-    // Alice mints badge during delay
-    env.ledger().with_mut(|li| {
-        li.timestamp += 43200;
-    });
-    valocracy_client.mint(&genesis_bob, &genesis_alice, &50);
-
-    // Fast forward to voting
-    env.ledger().with_mut(|li| {
-        li.timestamp = creation_time + 86401;
-    });
-
-    // ✅ CORRECT BEHAVIOR: Voting power SHOULD be from creation snapshot (100)
-    // NOT from voting start snapshot (600)
-
-    // Current implementation uses proposal.start_time for snapshot
-    // which gives 600 (wrong!)
-
-    // CORRECT implementation would store creation_time in proposal
-    // and use that for snapshot, giving 100 (right!)
-
-    let proposal = governor_client.get_proposal(&proposal_id).unwrap();
-
-    // To fix KRN-03, the proposal should store creation_time separately
-    // and cast_vote should use: get_votes_at(voter, proposal.creation_time)
-    // instead of: get_votes_at(voter, proposal.start_time)
+    assert_eq!(proposal_after.for_votes, 100);
 }
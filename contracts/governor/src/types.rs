@@ -1,4 +1,6 @@
-use soroban_sdk::{contracttype, Env};
+use soroban_sdk::{contracttype, Address, BytesN, Env};
+
+use crate::proposal::VoteType;
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -9,6 +11,10 @@ pub struct GovernanceConfig {
     pub quorum_percentage: u64,
     /// KRN-03: Prevents single-vote proposal hijacking
     pub participation_threshold: u64,
+    /// Timelock: seconds a queued proposal must wait before execution
+    pub execution_delay: u64,
+    /// Seconds after the ETA during which a queued proposal can still be executed
+    pub grace_period: u64,
 }
 
 impl GovernanceConfig {
@@ -19,6 +25,32 @@ impl GovernanceConfig {
             proposal_threshold: 100,    // 100 Mana
             quorum_percentage: 51,      // 51% approval required
             participation_threshold: 4, // 4% participation required (KRN-03)
+            execution_delay: 172800,    // 2 days timelock
+            grace_period: 1209600,      // 14 days to execute once ready
         }
     }
 }
+
+/// Message signed by a voter's registered key for `cast_vote_by_sig`, encoded as XDR.
+/// Binds the signature to one voter, one Governor deployment and one network.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteMessage {
+    pub version: u32,
+    pub contract: Address,
+    pub network_id: BytesN<32>,
+    pub voter: Address,
+    pub proposal_id: u64,
+    pub vote: VoteType,
+    pub nonce: u64,
+    pub expiry: u64,
+}
+
+/// Key a voter registers for `cast_vote_by_sig`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VotingKey {
+    pub public_key: BytesN<32>,
+    /// Timestamp after which signatures from the key are rejected
+    pub expires_at: u64,
+}
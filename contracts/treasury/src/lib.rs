@@ -5,9 +5,10 @@
 
 mod storage;
 mod vault;
+mod vesting;
 
 use soroban_sdk::{
-    contract, contracterror, contractevent, contractimpl, token, Address, BytesN, Env,
+    contract, contracterror, contractevent, contractimpl, token, Address, BytesN, Env, Vec,
 };
 
 use storage::{
@@ -18,27 +19,37 @@ use storage::{
     get_governor,
     // Lab Escrow
     get_lab,
+    get_lab_contributions,
     get_lab_counter,
     // KRN-01: Restricted reserves (scholarship funds)
     get_restricted_reserves,
+    get_scholarship,
+    get_scholarship_labs,
     get_total_shares,
     get_user_shares,
     get_valocracy,
+    is_legacy_lab,
     is_locked,
     release_lock,
     set_asset_token,
     set_claimable,
     set_governor,
     set_lab,
+    set_lab_contributions,
     set_lab_counter,
     set_restricted_reserves,
+    set_scholarship,
+    set_scholarship_labs,
     set_total_shares,
     set_user_shares,
     set_valocracy,
     Lab,
     LabStatus,
+    Scholarship,
 };
 
+pub use storage::{Contribution, Tranche, Vesting};
+
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
@@ -54,8 +65,18 @@ pub enum TreasuryError {
     LabNotFound = 9,
     LabNotActive = 10,
     InsufficientClaimable = 11,
+    LabBudgetExceeded = 12,
+    AlreadyApproved = 13,
+    InvalidVesting = 14,
+    TooManySponsors = 15,
+    LabNotLegacy = 16,
+    LabNotCancelled = 17,
+    ScholarshipNotFound = 18,
 }
 
+/// Maximum number of sponsors contributing to a single Lab.
+pub const MAX_LAB_SPONSORS: u32 = 20;
+
 #[contract]
 pub struct TreasuryContract;
 
@@ -70,6 +91,15 @@ pub enum TreasuryEvent {
         receiver: Address,
         shares: i128,
     },
+    DepositBatch {
+        receivers: u32,
+        shares: i128,
+    },
+    Burn {
+        #[topic]
+        holder: Address,
+        shares: i128,
+    },
     Transfer {
         #[topic]
         receiver: Address,
@@ -81,6 +111,13 @@ pub enum TreasuryEvent {
         funder: Address,
         total_amount: i128,
     },
+    LabToppedUp {
+        #[topic]
+        lab_id: u32,
+        funder: Address,
+        amount: i128,
+        total_amount: i128,
+    },
     ScholarshipReleased {
         #[topic]
         lab_id: u32,
@@ -88,6 +125,28 @@ pub enum TreasuryEvent {
         member: Address,
         amount: i128,
     },
+    LabCancelled {
+        #[topic]
+        lab_id: u32,
+        refunded: i128,
+    },
+    LabCompleted {
+        #[topic]
+        lab_id: u32,
+        refunded: i128,
+    },
+    LabBackfilled {
+        #[topic]
+        lab_id: u32,
+        allocated: i128,
+    },
+    ScholarshipClawedBack {
+        #[topic]
+        lab_id: u32,
+        #[topic]
+        member: Address,
+        amount: i128,
+    },
     ScholarshipWithdrawn {
         #[topic]
         member: Address,
@@ -166,6 +225,79 @@ impl TreasuryContract {
         Ok(())
     }
 
+    /// Allocate shares to several receivers at once — called by Valocracy for batch mints.
+    /// The minimum deposit rule applies to the batch total.
+    pub fn deposit_batch(env: Env, entries: Vec<(Address, i128)>) -> Result<(), TreasuryError> {
+        let valocracy = get_valocracy(&env).ok_or(TreasuryError::NotInitialized)?;
+        valocracy.require_auth();
+
+        let mut batch_shares: i128 = 0;
+        for (_, shares) in entries.iter() {
+            if shares <= 0 {
+                return Err(TreasuryError::ZeroAmount);
+            }
+            batch_shares = batch_shares
+                .checked_add(shares)
+                .ok_or(TreasuryError::MathOverflow)?;
+        }
+
+        let total = get_total_shares(&env);
+        vault::validate_deposit(batch_shares, total == 0)?;
+
+        for (receiver, shares) in entries.iter() {
+            let current_shares = get_user_shares(&env, &receiver);
+            let new_user_shares = current_shares
+                .checked_add(shares)
+                .ok_or(TreasuryError::MathOverflow)?;
+            set_user_shares(&env, &receiver, new_user_shares);
+
+            TreasuryEvent::Deposit { receiver, shares }.publish(&env);
+        }
+
+        let new_total = total
+            .checked_add(batch_shares)
+            .ok_or(TreasuryError::MathOverflow)?;
+        set_total_shares(&env, new_total);
+
+        extend_instance_ttl(&env);
+
+        TreasuryEvent::DepositBatch {
+            receivers: entries.len(),
+            shares: batch_shares,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Remove shares — called by Valocracy when badges are revoked or renounced.
+    pub fn burn(env: Env, holder: Address, shares: i128) -> Result<(), TreasuryError> {
+        let valocracy = get_valocracy(&env).ok_or(TreasuryError::NotInitialized)?;
+        valocracy.require_auth();
+
+        if shares <= 0 {
+            return Err(TreasuryError::ZeroAmount);
+        }
+
+        let current_shares = get_user_shares(&env, &holder);
+        if current_shares < shares {
+            return Err(TreasuryError::InsufficientShares);
+        }
+        set_user_shares(&env, &holder, current_shares - shares);
+
+        let total = get_total_shares(&env);
+        let new_total = total
+            .checked_sub(shares)
+            .ok_or(TreasuryError::MathOverflow)?;
+        set_total_shares(&env, new_total);
+
+        extend_instance_ttl(&env);
+
+        TreasuryEvent::Burn { holder, shares }.publish(&env);
+
+        Ok(())
+    }
+
     /// Withdraw is deprecated. All withdrawals must be approved through governance via `transfer()`.
     pub fn withdraw(
         _env: Env,
@@ -274,12 +406,31 @@ impl TreasuryContract {
 
     /// Fund a new Lab (Scholarship)
     ///
-    /// Only callable by the funder.
+    /// Only callable by the funder. Scholarships are claimable as soon as they are approved.
     pub fn fund_lab(
         env: Env,
         funder: Address,
         total_amount: i128,
         scholarship_per_member: i128,
+    ) -> Result<u32, TreasuryError> {
+        Self::fund_lab_with_vesting(
+            env,
+            funder,
+            total_amount,
+            scholarship_per_member,
+            Vesting::Immediate,
+        )
+    }
+
+    /// Fund a new Lab whose scholarships are released on a vesting schedule
+    ///
+    /// Only callable by the funder.
+    pub fn fund_lab_with_vesting(
+        env: Env,
+        funder: Address,
+        total_amount: i128,
+        scholarship_per_member: i128,
+        vesting: Vesting,
     ) -> Result<u32, TreasuryError> {
         funder.require_auth();
 
@@ -287,6 +438,10 @@ impl TreasuryContract {
             return Err(TreasuryError::ZeroAmount);
         }
 
+        if !vesting::is_valid(&vesting) {
+            return Err(TreasuryError::InvalidVesting);
+        }
+
         // Get or create lab ID
         let lab_id = get_lab_counter(&env);
         let new_lab_id = lab_id.checked_add(1).ok_or(TreasuryError::MathOverflow)?;
@@ -311,8 +466,22 @@ impl TreasuryContract {
             total_amount,
             scholarship_per_member,
             status: LabStatus::Active,
+            allocated: 0,
+            claimed: 0,
+            vesting,
         };
         set_lab(&env, &lab);
+        set_lab_contributions(
+            &env,
+            new_lab_id,
+            &Vec::from_array(
+                &env,
+                [Contribution {
+                    sponsor: funder.clone(),
+                    amount: total_amount,
+                }],
+            ),
+        );
 
         extend_instance_ttl(&env);
 
@@ -326,7 +495,83 @@ impl TreasuryContract {
         Ok(new_lab_id)
     }
 
-    /// Releases scholarship funds to a member's claimable balance (Governor only).
+    /// Add funds to an active Lab. Any sponsor may contribute.
+    pub fn top_up_lab(
+        env: Env,
+        lab_id: u32,
+        funder: Address,
+        amount: i128,
+    ) -> Result<(), TreasuryError> {
+        funder.require_auth();
+
+        if amount <= 0 {
+            return Err(TreasuryError::ZeroAmount);
+        }
+
+        let mut lab = get_lab(&env, lab_id).ok_or(TreasuryError::LabNotFound)?;
+        if lab.status != LabStatus::Active {
+            return Err(TreasuryError::LabNotActive);
+        }
+
+        // Record the contribution
+        let mut contributions = Self::contributions_of(&env, &lab);
+        match contributions.iter().position(|c| c.sponsor == funder) {
+            Some(index) => {
+                let mut contribution = contributions.get_unchecked(index as u32);
+                contribution.amount = contribution
+                    .amount
+                    .checked_add(amount)
+                    .ok_or(TreasuryError::MathOverflow)?;
+                contributions.set(index as u32, contribution);
+            }
+            None => {
+                if contributions.len() >= MAX_LAB_SPONSORS {
+                    return Err(TreasuryError::TooManySponsors);
+                }
+                contributions.push_back(Contribution {
+                    sponsor: funder.clone(),
+                    amount,
+                });
+            }
+        }
+        set_lab_contributions(&env, lab_id, &contributions);
+
+        lab.total_amount = lab
+            .total_amount
+            .checked_add(amount)
+            .ok_or(TreasuryError::MathOverflow)?;
+        set_lab(&env, &lab);
+
+        // Transfer funds from funder to treasury
+        let asset_token = get_asset_token(&env).ok_or(TreasuryError::NotInitialized)?;
+        let client = token::TokenClient::new(&env, &asset_token);
+        client.transfer(&funder, env.current_contract_address(), &amount);
+
+        // KRN-01: Increment restricted reserves (escrowed for scholarships)
+        let current_restricted = get_restricted_reserves(&env);
+        let new_restricted = current_restricted
+            .checked_add(amount)
+            .ok_or(TreasuryError::MathOverflow)?;
+        set_restricted_reserves(&env, new_restricted);
+
+        extend_instance_ttl(&env);
+
+        TreasuryEvent::LabToppedUp {
+            lab_id,
+            funder,
+            amount,
+            total_amount: lab.total_amount,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Grants a member a scholarship from a Lab (Governor only).
+    /// The funds become claimable according to the lab's vesting schedule.
+    ///
+    /// Each member can be approved once per lab, within the lab's budget. The lab is
+    /// completed once its remaining escrow cannot fund another scholarship.
     pub fn approve_scholarship(
         env: Env,
         lab_id: u32,
@@ -336,22 +581,41 @@ impl TreasuryContract {
         governor.require_auth();
 
         // Get lab
-        let lab = get_lab(&env, lab_id).ok_or(TreasuryError::LabNotFound)?;
+        let mut lab = get_lab(&env, lab_id).ok_or(TreasuryError::LabNotFound)?;
 
         // Verify lab is active
         if lab.status != LabStatus::Active {
             return Err(TreasuryError::LabNotActive);
         }
 
+        if get_scholarship(&env, lab_id, &member).is_some() {
+            return Err(TreasuryError::AlreadyApproved);
+        }
+
         // Calculate scholarship amount
         let scholarship_amount = lab.scholarship_per_member;
-
-        // Increase member's claimable balance
-        let current_claimable = get_claimable(&env, &member);
-        let new_claimable = current_claimable
+        let new_allocated = lab
+            .allocated
             .checked_add(scholarship_amount)
             .ok_or(TreasuryError::MathOverflow)?;
-        set_claimable(&env, &member, new_claimable);
+        if new_allocated > lab.total_amount {
+            return Err(TreasuryError::LabBudgetExceeded);
+        }
+
+        // Record the scholarship against the lab
+        let scholarship = Scholarship {
+            amount: scholarship_amount,
+            claimed: 0,
+            approved_at: env.ledger().timestamp(),
+            clawed_back: false,
+        };
+        set_scholarship(&env, lab_id, &member, &scholarship);
+        let mut labs = get_scholarship_labs(&env, &member);
+        labs.push_back(lab_id);
+        set_scholarship_labs(&env, &member, &labs);
+
+        lab.allocated = new_allocated;
+        set_lab(&env, &lab);
 
         extend_instance_ttl(&env);
 
@@ -362,12 +626,184 @@ impl TreasuryContract {
         }
         .publish(&env);
 
+        if lab.total_amount - lab.allocated < lab.scholarship_per_member {
+            let refunded = Self::close_lab(&env, lab, LabStatus::Completed)?;
+            TreasuryEvent::LabCompleted { lab_id, refunded }.publish(&env);
+        }
+
         Ok(())
     }
 
-    /// Get claimable scholarship balance.
-    pub fn get_claimable_balance(env: Env, member: Address) -> i128 {
-        get_claimable(&env, &member)
+    /// Cancel an active Lab and refund its unallocated escrow to the funder.
+    ///
+    /// Callable by the funder or the governor. Scholarships already approved stay claimable;
+    /// the governor can claw back their unvested part with `clawback_unvested`.
+    pub fn cancel_lab(env: Env, caller: Address, lab_id: u32) -> Result<i128, TreasuryError> {
+        caller.require_auth();
+
+        let lab = get_lab(&env, lab_id).ok_or(TreasuryError::LabNotFound)?;
+        let governor = get_governor(&env).ok_or(TreasuryError::NotInitialized)?;
+        if caller != lab.funder && caller != governor {
+            return Err(TreasuryError::NotAuthorized);
+        }
+
+        let refunded = Self::close_lab(&env, lab, LabStatus::Cancelled)?;
+
+        TreasuryEvent::LabCancelled { lab_id, refunded }.publish(&env);
+
+        Ok(refunded)
+    }
+
+    /// Mark an active Lab as completed and refund its unallocated escrow to the funder
+    /// (Governor only).
+    pub fn complete_lab(env: Env, lab_id: u32) -> Result<i128, TreasuryError> {
+        let governor = get_governor(&env).ok_or(TreasuryError::NotInitialized)?;
+        governor.require_auth();
+
+        let lab = get_lab(&env, lab_id).ok_or(TreasuryError::LabNotFound)?;
+        let refunded = Self::close_lab(&env, lab, LabStatus::Completed)?;
+
+        TreasuryEvent::LabCompleted { lab_id, refunded }.publish(&env);
+
+        Ok(refunded)
+    }
+
+    /// Claw back the unvested part of a member's scholarship from a cancelled Lab and
+    /// refund it to the lab's sponsors (Governor only). Returns the refunded amount.
+    ///
+    /// The amount vested so far stays claimable and no longer vests further.
+    pub fn clawback_unvested(
+        env: Env,
+        lab_id: u32,
+        member: Address,
+    ) -> Result<i128, TreasuryError> {
+        let governor = get_governor(&env).ok_or(TreasuryError::NotInitialized)?;
+        governor.require_auth();
+
+        let mut lab = get_lab(&env, lab_id).ok_or(TreasuryError::LabNotFound)?;
+        if lab.status != LabStatus::Cancelled {
+            return Err(TreasuryError::LabNotCancelled);
+        }
+
+        let mut scholarship =
+            get_scholarship(&env, lab_id, &member).ok_or(TreasuryError::ScholarshipNotFound)?;
+        let vested = Self::vested_of(&env, lab_id, &scholarship)?;
+        let unvested = scholarship
+            .amount
+            .checked_sub(vested)
+            .ok_or(TreasuryError::MathOverflow)?;
+        if unvested <= 0 {
+            return Err(TreasuryError::ZeroAmount);
+        }
+
+        scholarship.amount = vested;
+        scholarship.clawed_back = true;
+        set_scholarship(&env, lab_id, &member, &scholarship);
+        if scholarship.claimed >= scholarship.amount {
+            let mut labs = get_scholarship_labs(&env, &member);
+            if let Some(index) = labs.first_index_of(lab_id) {
+                labs.remove(index);
+                set_scholarship_labs(&env, &member, &labs);
+            }
+        }
+
+        lab.allocated = lab
+            .allocated
+            .checked_sub(unvested)
+            .ok_or(TreasuryError::MathOverflow)?;
+        set_lab(&env, &lab);
+
+        // KRN-01: Release the clawed back escrow from restricted reserves
+        let current_restricted = get_restricted_reserves(&env);
+        let new_restricted = current_restricted
+            .checked_sub(unvested)
+            .ok_or(TreasuryError::MathOverflow)?;
+        set_restricted_reserves(&env, new_restricted);
+
+        Self::refund_sponsors(&env, &lab, unvested)?;
+
+        extend_instance_ttl(&env);
+
+        TreasuryEvent::ScholarshipClawedBack {
+            lab_id,
+            member,
+            amount: unvested,
+        }
+        .publish(&env);
+
+        Ok(unvested)
+    }
+
+    /// Record the escrow already released by an active Lab funded before allocations
+    /// were tracked (Governor only).
+    ///
+    /// Such labs are read as fully allocated, so nothing is refunded on closing
+    /// until the real amount is backfilled. Each lab can be backfilled once.
+    pub fn backfill_lab(env: Env, lab_id: u32, allocated: i128) -> Result<(), TreasuryError> {
+        let governor = get_governor(&env).ok_or(TreasuryError::NotInitialized)?;
+        governor.require_auth();
+
+        let mut lab = get_lab(&env, lab_id).ok_or(TreasuryError::LabNotFound)?;
+        if !is_legacy_lab(&env, lab_id) {
+            return Err(TreasuryError::LabNotLegacy);
+        }
+        if lab.status != LabStatus::Active {
+            return Err(TreasuryError::LabNotActive);
+        }
+        if allocated < 0 || allocated > lab.total_amount {
+            return Err(TreasuryError::LabBudgetExceeded);
+        }
+
+        lab.allocated = allocated;
+        set_lab(&env, &lab);
+        extend_instance_ttl(&env);
+
+        TreasuryEvent::LabBackfilled { lab_id, allocated }.publish(&env);
+
+        Ok(())
+    }
+
+    /// Get a Lab by ID.
+    pub fn lab(env: Env, lab_id: u32) -> Option<Lab> {
+        get_lab(&env, lab_id)
+    }
+
+    /// Get the contributions of a Lab's sponsors.
+    pub fn lab_contributions(env: Env, lab_id: u32) -> Result<Vec<Contribution>, TreasuryError> {
+        let lab = get_lab(&env, lab_id).ok_or(TreasuryError::LabNotFound)?;
+        Ok(Self::contributions_of(&env, &lab))
+    }
+
+    /// Get claimable scholarship balance across all labs, including any balance
+    /// pooled before scholarships were tracked per lab.
+    pub fn get_claimable_balance(env: Env, member: Address) -> Result<i128, TreasuryError> {
+        let mut total = get_claimable(&env, &member);
+        for lab_id in get_scholarship_labs(&env, &member).iter() {
+            let claimable = Self::claimable_for(env.clone(), lab_id, member.clone())?;
+            total = total
+                .checked_add(claimable)
+                .ok_or(TreasuryError::MathOverflow)?;
+        }
+        Ok(total)
+    }
+
+    /// Get the vested scholarship amount of a member from a Lab, including withdrawals.
+    pub fn vested_amount(env: Env, lab_id: u32, member: Address) -> Result<i128, TreasuryError> {
+        match get_scholarship(&env, lab_id, &member) {
+            Some(scholarship) => Self::vested_of(&env, lab_id, &scholarship),
+            None => Ok(0),
+        }
+    }
+
+    /// Get the scholarship amount a member can currently withdraw from a Lab.
+    pub fn claimable_for(env: Env, lab_id: u32, member: Address) -> Result<i128, TreasuryError> {
+        match get_scholarship(&env, lab_id, &member) {
+            Some(scholarship) => {
+                let vested = Self::vested_of(&env, lab_id, &scholarship)?;
+                Ok(vested.saturating_sub(scholarship.claimed))
+            }
+            None => Ok(0),
+        }
     }
 
     /// Withdraw approved scholarship funds.
@@ -382,17 +818,8 @@ impl TreasuryContract {
             return Err(TreasuryError::ZeroAmount);
         }
 
-        // Check claimable balance
-        let claimable = get_claimable(&env, &member);
-        if claimable < amount {
-            return Err(TreasuryError::InsufficientClaimable);
-        }
-
-        // Reduce claimable balance
-        let new_claimable = claimable
-            .checked_sub(amount)
-            .ok_or(TreasuryError::MathOverflow)?;
-        set_claimable(&env, &member, new_claimable);
+        // Draw from vested scholarships
+        Self::record_claim(&env, &member, amount)?;
 
         // KRN-01: Decrement restricted reserves
         let current_restricted = get_restricted_reserves(&env);
@@ -413,6 +840,139 @@ impl TreasuryContract {
         Ok(())
     }
 
+    /// Vested amount of a scholarship under its lab's schedule.
+    fn vested_of(env: &Env, lab_id: u32, scholarship: &Scholarship) -> Result<i128, TreasuryError> {
+        if scholarship.clawed_back {
+            return Ok(scholarship.amount);
+        }
+        let lab = get_lab(env, lab_id).ok_or(TreasuryError::LabNotFound)?;
+        vesting::vested(
+            &lab.vesting,
+            scholarship.amount,
+            scholarship.approved_at,
+            env.ledger().timestamp(),
+        )
+    }
+
+    /// Draw a withdrawal from the member's legacy pooled balance, then from their vested
+    /// scholarships, oldest approval first. Fails if less than `amount` is claimable.
+    fn record_claim(env: &Env, member: &Address, amount: i128) -> Result<(), TreasuryError> {
+        let pooled = get_claimable(env, member);
+        let from_pool = pooled.min(amount);
+        let mut remaining = amount - from_pool;
+
+        let mut open_labs = Vec::new(env);
+        for lab_id in get_scholarship_labs(env, member).iter() {
+            let Some(mut scholarship) = get_scholarship(env, lab_id, member) else {
+                continue;
+            };
+            let vested = Self::vested_of(env, lab_id, &scholarship)?;
+            let taken = vested.saturating_sub(scholarship.claimed).min(remaining);
+            if taken > 0 {
+                scholarship.claimed += taken;
+                set_scholarship(env, lab_id, member, &scholarship);
+
+                let mut lab = get_lab(env, lab_id).ok_or(TreasuryError::LabNotFound)?;
+                lab.claimed = lab
+                    .claimed
+                    .checked_add(taken)
+                    .ok_or(TreasuryError::MathOverflow)?;
+                set_lab(env, &lab);
+
+                remaining -= taken;
+            }
+            if scholarship.claimed < scholarship.amount {
+                open_labs.push_back(lab_id);
+            }
+        }
+        if remaining > 0 {
+            return Err(TreasuryError::InsufficientClaimable);
+        }
+        set_claimable(env, member, pooled - from_pool);
+        set_scholarship_labs(env, member, &open_labs);
+        Ok(())
+    }
+
+    /// Contributions to a Lab. Labs without records were funded by their funder alone.
+    fn contributions_of(env: &Env, lab: &Lab) -> Vec<Contribution> {
+        let contributions = get_lab_contributions(env, lab.id);
+        if !contributions.is_empty() {
+            return contributions;
+        }
+        Vec::from_array(
+            env,
+            [Contribution {
+                sponsor: lab.funder.clone(),
+                amount: lab.total_amount,
+            }],
+        )
+    }
+
+    /// Return `amount` of a Lab's escrow to its sponsors pro rata.
+    /// Rounding dust goes to the first sponsor, the lab's funder.
+    fn refund_sponsors(env: &Env, lab: &Lab, amount: i128) -> Result<(), TreasuryError> {
+        let asset_token = get_asset_token(env).ok_or(TreasuryError::NotInitialized)?;
+        let client = token::TokenClient::new(env, &asset_token);
+
+        let contributions = Self::contributions_of(env, lab);
+        let mut shares = Vec::new(env);
+        let mut distributed: i128 = 0;
+        for contribution in contributions.iter() {
+            let share = amount
+                .checked_mul(contribution.amount)
+                .ok_or(TreasuryError::MathOverflow)?
+                / lab.total_amount;
+            distributed += share;
+            shares.push_back(share);
+        }
+        for (index, contribution) in contributions.iter().enumerate() {
+            let mut share = shares.get_unchecked(index as u32);
+            if index == 0 {
+                share += amount - distributed;
+            }
+            if share > 0 {
+                client.transfer(
+                    &env.current_contract_address(),
+                    &contribution.sponsor,
+                    &share,
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Close an active Lab with `status`, refunding the escrow not yet allocated to its
+    /// sponsors pro rata. Returns the refunded amount.
+    fn close_lab(env: &Env, mut lab: Lab, status: LabStatus) -> Result<i128, TreasuryError> {
+        if lab.status != LabStatus::Active {
+            return Err(TreasuryError::LabNotActive);
+        }
+
+        lab.status = status;
+        set_lab(env, &lab);
+
+        let refunded = lab
+            .total_amount
+            .checked_sub(lab.allocated)
+            .ok_or(TreasuryError::MathOverflow)?
+            .max(0);
+
+        if refunded > 0 {
+            // KRN-01: Release the refunded escrow from restricted reserves
+            let current_restricted = get_restricted_reserves(env);
+            let new_restricted = current_restricted
+                .checked_sub(refunded)
+                .ok_or(TreasuryError::MathOverflow)?;
+            set_restricted_reserves(env, new_restricted);
+
+            Self::refund_sponsors(env, &lab, refunded)?;
+        }
+
+        extend_instance_ttl(env);
+
+        Ok(refunded)
+    }
+
     /// Upgrade the contract to a new WASM hash.
     /// Only callable by the governor (requires governance proposal).
     pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), TreasuryError> {
@@ -1,6 +1,6 @@
 //! Storage helpers for the Treasury contract
 
-use soroban_sdk::{contracttype, Address, Env};
+use soroban_sdk::{contracttype, Address, Env, Map, Symbol, TryFromVal, Val, Vec};
 
 /// Storage keys for the Treasury contract
 #[contracttype]
@@ -18,9 +18,15 @@ pub enum DataKey {
     LabCounter,
     /// Lab ID -> Lab
     Lab(u32),
-    /// User address -> Claimable balance (Scholarship funds)
+    /// User address -> Claimable balance pooled before per-lab scholarships (legacy)
     ClaimableBalance(Address),
     RestrictedReserves,
+    /// (Lab ID, member) -> approved scholarship
+    Scholarship(u32, Address),
+    /// Lab ID -> contributions of its sponsors
+    LabContributions(u32),
+    /// Member -> IDs of labs with a scholarship not fully withdrawn, in approval order
+    ScholarshipLabs(Address),
 }
 
 /// Status of a Lab
@@ -32,6 +38,47 @@ pub enum LabStatus {
     Completed,
 }
 
+/// Release of a scholarship after its approval.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Vesting {
+    /// Fully claimable on approval
+    Immediate,
+    /// Released linearly over a duration in seconds
+    Linear(u64),
+    /// Released in tranches
+    Tranches(Vec<Tranche>),
+}
+
+/// Share of a scholarship unlocked a fixed time after approval.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Tranche {
+    /// Seconds after approval
+    pub unlocks_after: u64,
+    /// Share of the scholarship in basis points
+    pub bps: u32,
+}
+
+/// Amount a sponsor put into a Lab.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Contribution {
+    pub sponsor: Address,
+    pub amount: i128,
+}
+
+/// A member's scholarship from one Lab.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Scholarship {
+    pub amount: i128,
+    pub claimed: i128,
+    pub approved_at: u64,
+    /// Unvested remainder was clawed back, so `amount` is fully vested
+    pub clawed_back: bool,
+}
+
 /// Lab Funding Struct
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -41,6 +88,40 @@ pub struct Lab {
     pub total_amount: i128,
     pub scholarship_per_member: i128,
     pub status: LabStatus,
+    /// Escrow released to members' claimable balances
+    pub allocated: i128,
+    /// Allocated escrow withdrawn by members
+    pub claimed: i128,
+    /// Release schedule of scholarships approved from this lab
+    pub vesting: Vesting,
+}
+
+/// Lab as stored before allocations, claims and vesting were tracked.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LabV1 {
+    pub id: u32,
+    pub funder: Address,
+    pub total_amount: i128,
+    pub scholarship_per_member: i128,
+    pub status: LabStatus,
+}
+
+impl LabV1 {
+    /// Scholarships released from a legacy lab were not recorded, so its whole
+    /// escrow is treated as allocated until the governor backfills the real amount.
+    fn upgrade(self) -> Lab {
+        Lab {
+            id: self.id,
+            funder: self.funder,
+            total_amount: self.total_amount,
+            scholarship_per_member: self.scholarship_per_member,
+            status: self.status,
+            allocated: self.total_amount,
+            claimed: 0,
+            vesting: Vesting::Immediate,
+        }
+    }
 }
 
 // TTL constants
@@ -141,6 +222,21 @@ pub fn release_lock(env: &Env) {
     env.storage().instance().remove(&DataKey::ReentrancyLock);
 }
 
+pub fn get_claimable(env: &Env, account: &Address) -> i128 {
+    let key = DataKey::ClaimableBalance(account.clone());
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+pub fn set_claimable(env: &Env, account: &Address, amount: i128) {
+    let key = DataKey::ClaimableBalance(account.clone());
+    if amount == 0 {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, &amount);
+    extend_persistent_ttl(env, &key);
+}
+
 pub fn get_lab_counter(env: &Env) -> u32 {
     env.storage()
         .instance()
@@ -152,9 +248,32 @@ pub fn set_lab_counter(env: &Env, count: u32) {
     env.storage().instance().set(&DataKey::LabCounter, &count);
 }
 
-pub fn get_lab(env: &Env, lab_id: u32) -> Option<Lab> {
+/// Stored fields of a Lab, or `None` if it does not exist.
+fn get_lab_fields(env: &Env, lab_id: u32) -> Option<Map<Symbol, Val>> {
     let key = DataKey::Lab(lab_id);
-    env.storage().persistent().get(&key)
+    let raw: Val = env.storage().persistent().get(&key)?;
+    Map::try_from_val(env, &raw).ok()
+}
+
+/// Labs stored before allocations were tracked lack the `allocated` field.
+fn is_legacy_fields(env: &Env, fields: &Map<Symbol, Val>) -> bool {
+    !fields.contains_key(Symbol::new(env, "allocated"))
+}
+
+/// Get a Lab, reading labs stored in the legacy format as fully allocated.
+pub fn get_lab(env: &Env, lab_id: u32) -> Option<Lab> {
+    let fields = get_lab_fields(env, lab_id)?;
+    if is_legacy_fields(env, &fields) {
+        return LabV1::try_from_val(env, &fields.to_val())
+            .ok()
+            .map(LabV1::upgrade);
+    }
+    Lab::try_from_val(env, &fields.to_val()).ok()
+}
+
+/// Check whether a Lab is still stored in the legacy format.
+pub fn is_legacy_lab(env: &Env, lab_id: u32) -> bool {
+    get_lab_fields(env, lab_id).is_some_and(|fields| is_legacy_fields(env, &fields))
 }
 
 pub fn set_lab(env: &Env, lab: &Lab) {
@@ -163,13 +282,45 @@ pub fn set_lab(env: &Env, lab: &Lab) {
     extend_persistent_ttl(env, &key);
 }
 
-pub fn get_claimable(env: &Env, account: &Address) -> i128 {
-    let key = DataKey::ClaimableBalance(account.clone());
-    env.storage().persistent().get(&key).unwrap_or(0)
+pub fn get_lab_contributions(env: &Env, lab_id: u32) -> Vec<Contribution> {
+    let key = DataKey::LabContributions(lab_id);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env))
 }
 
-pub fn set_claimable(env: &Env, account: &Address, amount: i128) {
-    let key = DataKey::ClaimableBalance(account.clone());
-    env.storage().persistent().set(&key, &amount);
+pub fn set_lab_contributions(env: &Env, lab_id: u32, contributions: &Vec<Contribution>) {
+    let key = DataKey::LabContributions(lab_id);
+    env.storage().persistent().set(&key, contributions);
+    extend_persistent_ttl(env, &key);
+}
+
+pub fn get_scholarship(env: &Env, lab_id: u32, member: &Address) -> Option<Scholarship> {
+    let key = DataKey::Scholarship(lab_id, member.clone());
+    env.storage().persistent().get(&key)
+}
+
+pub fn set_scholarship(env: &Env, lab_id: u32, member: &Address, scholarship: &Scholarship) {
+    let key = DataKey::Scholarship(lab_id, member.clone());
+    env.storage().persistent().set(&key, scholarship);
     extend_persistent_ttl(env, &key);
 }
+
+pub fn get_scholarship_labs(env: &Env, member: &Address) -> Vec<u32> {
+    let key = DataKey::ScholarshipLabs(member.clone());
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_scholarship_labs(env: &Env, member: &Address, labs: &Vec<u32>) {
+    let key = DataKey::ScholarshipLabs(member.clone());
+    if labs.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, labs);
+        extend_persistent_ttl(env, &key);
+    }
+}
@@ -1,5 +1,5 @@
 use super::*;
-use soroban_sdk::{testutils::Address as _, Address, Env};
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, Vec};
 
 #[test]
 fn test_math_overflow_protection() {
@@ -113,3 +113,79 @@ fn test_checked_math_logic() {
     // They are inside `impl TreasuryContract`.
     // `preview_withdraw` is public.
 }
+
+#[test]
+fn test_burn_shares() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TreasuryContract);
+    let client = TreasuryContractClient::new(&env, &contract_id);
+
+    let valocracy = Address::generate(&env);
+    let governor = Address::generate(&env);
+    let asset = Address::generate(&env);
+    client.initialize(&valocracy, &governor, &asset);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.deposit(&alice, &1000);
+    client.deposit(&bob, &500);
+
+    client.burn(&alice, &400);
+    assert_eq!(client.shares_of(&alice), 600);
+    assert_eq!(client.shares_of(&bob), 500);
+    assert_eq!(client.total_shares(), 1100);
+
+    assert_eq!(
+        client.try_burn(&bob, &501),
+        Err(Ok(TreasuryError::InsufficientShares))
+    );
+    assert_eq!(
+        client.try_burn(&bob, &0),
+        Err(Ok(TreasuryError::ZeroAmount))
+    );
+}
+
+#[test]
+fn test_deposit_batch() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TreasuryContract);
+    let client = TreasuryContractClient::new(&env, &contract_id);
+
+    let valocracy = Address::generate(&env);
+    let governor = Address::generate(&env);
+    let asset = Address::generate(&env);
+    client.initialize(&valocracy, &governor, &asset);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    // The minimum first deposit applies to the batch total, not to each entry
+    client.deposit_batch(&vec![&env, (alice.clone(), 600), (bob.clone(), 400)]);
+    assert_eq!(client.shares_of(&alice), 600);
+    assert_eq!(client.shares_of(&bob), 400);
+    assert_eq!(client.total_shares(), 1000);
+
+    // Repeated receivers accumulate
+    client.deposit_batch(&vec![&env, (alice.clone(), 5), (alice.clone(), 5)]);
+    assert_eq!(client.shares_of(&alice), 610);
+    assert_eq!(client.total_shares(), 1010);
+
+    // A single invalid entry rejects the whole batch
+    assert_eq!(
+        client.try_deposit_batch(&vec![&env, (alice.clone(), 5), (bob.clone(), 0)]),
+        Err(Ok(TreasuryError::ZeroAmount))
+    );
+    assert_eq!(
+        client.try_deposit_batch(&vec![&env, (alice.clone(), i128::MAX), (bob.clone(), 1)]),
+        Err(Ok(TreasuryError::MathOverflow))
+    );
+    assert_eq!(
+        client.try_deposit_batch(&Vec::new(&env)),
+        Err(Ok(TreasuryError::ZeroAmount))
+    );
+    assert_eq!(client.total_shares(), 1010);
+}
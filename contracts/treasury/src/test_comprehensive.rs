@@ -17,7 +17,10 @@
 
 use super::*;
 use crate::vault::MIN_INITIAL_DEPOSIT;
-use soroban_sdk::{testutils::Address as _, token, Address, BytesN, Env};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, BytesN, Env,
+};
 
 // ============ Test Helpers ============
 
@@ -497,6 +500,470 @@ fn test_fund_lab_zero_scholarship_fails() {
     assert_eq!(result.unwrap_err().unwrap(), TreasuryError::ZeroAmount);
 }
 
+// ============ Lab Lifecycle Tests ============
+
+#[test]
+fn test_cancel_lab_refunds_unallocated_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, contract_id, _, _, token_id, token_client) = setup_treasury(&env);
+    let admin_client = token::StellarAssetClient::new(&env, &token_id);
+
+    let funder = Address::generate(&env);
+    admin_client.mint(&funder, &10_000);
+    let lab_id = client.fund_lab(&funder, &10_000, &1_000);
+
+    let student = Address::generate(&env);
+    client.approve_scholarship(&lab_id, &student);
+
+    // The funder gets back everything not yet approved
+    assert_eq!(client.cancel_lab(&funder, &lab_id), 9_000);
+    assert_eq!(token_client.balance(&funder), 9_000);
+    assert_eq!(client.lab(&lab_id).unwrap().status, LabStatus::Cancelled);
+
+    // The approved scholarship is still escrowed and claimable
+    assert_eq!(token_client.balance(&contract_id), 1_000);
+    assert_eq!(client.total_assets(), 0);
+    client.withdraw_scholarship(&student, &1_000);
+    assert_eq!(token_client.balance(&student), 1_000);
+
+    // A closed lab cannot be approved from or closed again
+    assert_eq!(
+        client.try_approve_scholarship(&lab_id, &student),
+        Err(Ok(TreasuryError::LabNotActive))
+    );
+    assert_eq!(
+        client.try_cancel_lab(&funder, &lab_id),
+        Err(Ok(TreasuryError::LabNotActive))
+    );
+}
+
+#[test]
+fn test_cancel_lab_requires_funder_or_governor() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _, _, governor, token_id, token_client) = setup_treasury(&env);
+    let admin_client = token::StellarAssetClient::new(&env, &token_id);
+
+    let funder = Address::generate(&env);
+    admin_client.mint(&funder, &5_000);
+    let lab_id = client.fund_lab(&funder, &5_000, &1_000);
+
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_cancel_lab(&stranger, &lab_id),
+        Err(Ok(TreasuryError::NotAuthorized))
+    );
+    assert_eq!(
+        client.try_cancel_lab(&stranger, &99),
+        Err(Ok(TreasuryError::LabNotFound))
+    );
+
+    // The refund always goes to the funder
+    assert_eq!(client.cancel_lab(&governor, &lab_id), 5_000);
+    assert_eq!(token_client.balance(&funder), 5_000);
+}
+
+#[test]
+fn test_complete_lab_refunds_remainder() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, contract_id, _, _, token_id, token_client) = setup_treasury(&env);
+    let admin_client = token::StellarAssetClient::new(&env, &token_id);
+
+    let funder = Address::generate(&env);
+    admin_client.mint(&funder, &3_000);
+    let lab_id = client.fund_lab(&funder, &3_000, &1_000);
+
+    client.approve_scholarship(&lab_id, &Address::generate(&env));
+    client.approve_scholarship(&lab_id, &Address::generate(&env));
+
+    assert_eq!(client.complete_lab(&lab_id), 1_000);
+    assert_eq!(client.lab(&lab_id).unwrap().status, LabStatus::Completed);
+    assert_eq!(token_client.balance(&funder), 1_000);
+    assert_eq!(token_client.balance(&contract_id), 2_000);
+    assert_eq!(client.total_assets(), 0);
+}
+
+#[test]
+fn test_approve_scholarship_enforces_budget() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _, _, _, token_id, token_client) = setup_treasury(&env);
+    let admin_client = token::StellarAssetClient::new(&env, &token_id);
+
+    let funder = Address::generate(&env);
+    admin_client.mint(&funder, &3_000);
+    let lab_id = client.fund_lab(&funder, &2_500, &1_000);
+    let small_lab = client.fund_lab(&funder, &500, &1_000);
+
+    let student = Address::generate(&env);
+    client.approve_scholarship(&lab_id, &student);
+    assert_eq!(
+        client.try_approve_scholarship(&lab_id, &student),
+        Err(Ok(TreasuryError::AlreadyApproved))
+    );
+    assert_eq!(
+        client.try_approve_scholarship(&small_lab, &student),
+        Err(Ok(TreasuryError::LabBudgetExceeded))
+    );
+
+    // The second approval leaves too little for a third, which completes the lab
+    client.approve_scholarship(&lab_id, &Address::generate(&env));
+    let lab = client.lab(&lab_id).unwrap();
+    assert_eq!(lab.allocated, 2_000);
+    assert_eq!(lab.status, LabStatus::Completed);
+    assert_eq!(token_client.balance(&funder), 500);
+    assert_eq!(
+        client.try_approve_scholarship(&lab_id, &Address::generate(&env)),
+        Err(Ok(TreasuryError::LabNotActive))
+    );
+}
+
+#[test]
+fn test_withdrawals_are_attributed_to_labs() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _, _, _, token_id, _) = setup_treasury(&env);
+    let admin_client = token::StellarAssetClient::new(&env, &token_id);
+
+    let funder = Address::generate(&env);
+    admin_client.mint(&funder, &20_000);
+    let lab1 = client.fund_lab(&funder, &10_000, &1_000);
+    let lab2 = client.fund_lab(&funder, &10_000, &2_000);
+
+    let student = Address::generate(&env);
+    client.approve_scholarship(&lab1, &student);
+    client.approve_scholarship(&lab2, &student);
+
+    // The oldest approval is drawn down first
+    client.withdraw_scholarship(&student, &1_500);
+    assert_eq!(client.lab(&lab1).unwrap().claimed, 1_000);
+    assert_eq!(client.lab(&lab2).unwrap().claimed, 500);
+
+    client.withdraw_scholarship(&student, &1_500);
+    assert_eq!(client.lab(&lab2).unwrap().claimed, 2_000);
+    assert_eq!(client.lab(&lab2).unwrap().allocated, 2_000);
+}
+
+#[test]
+fn test_top_up_lab_refunds_sponsors_pro_rata() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _, _, _, token_id, token_client) = setup_treasury(&env);
+    let admin_client = token::StellarAssetClient::new(&env, &token_id);
+
+    let lead = Address::generate(&env);
+    let cosponsor = Address::generate(&env);
+    admin_client.mint(&lead, &6_000);
+    admin_client.mint(&cosponsor, &4_000);
+
+    let lab_id = client.fund_lab(&lead, &3_000, &1_000);
+    client.top_up_lab(&lab_id, &cosponsor, &4_000);
+    client.top_up_lab(&lab_id, &lead, &3_000);
+
+    assert_eq!(client.lab(&lab_id).unwrap().total_amount, 10_000);
+    assert_eq!(
+        client.lab_contributions(&lab_id),
+        vec![
+            &env,
+            Contribution {
+                sponsor: lead.clone(),
+                amount: 6_000,
+            },
+            Contribution {
+                sponsor: cosponsor.clone(),
+                amount: 4_000,
+            },
+        ]
+    );
+    assert_eq!(client.total_assets(), 0);
+
+    // Unallocated escrow is split by contribution
+    client.approve_scholarship(&lab_id, &Address::generate(&env));
+    assert_eq!(client.cancel_lab(&lead, &lab_id), 9_000);
+    assert_eq!(token_client.balance(&lead), 5_400);
+    assert_eq!(token_client.balance(&cosponsor), 3_600);
+
+    assert_eq!(
+        client.try_top_up_lab(&lab_id, &cosponsor, &1_000),
+        Err(Ok(TreasuryError::LabNotActive))
+    );
+}
+
+#[test]
+fn test_pro_rata_refund_rounding() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, contract_id, _, _, token_id, token_client) = setup_treasury(&env);
+    let admin_client = token::StellarAssetClient::new(&env, &token_id);
+
+    let sponsors = [
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    for sponsor in sponsors.iter() {
+        admin_client.mint(sponsor, &1_000);
+    }
+
+    let lab_id = client.fund_lab(&sponsors[0], &1_000, &100);
+    client.top_up_lab(&lab_id, &sponsors[1], &1_000);
+    client.top_up_lab(&lab_id, &sponsors[2], &1_000);
+    assert_eq!(
+        client.try_top_up_lab(&lab_id, &sponsors[1], &0),
+        Err(Ok(TreasuryError::ZeroAmount))
+    );
+
+    // 2_900 does not split evenly; the dust goes to the lab's funder
+    client.approve_scholarship(&lab_id, &Address::generate(&env));
+    assert_eq!(client.complete_lab(&lab_id), 2_900);
+    assert_eq!(token_client.balance(&sponsors[0]), 968);
+    assert_eq!(token_client.balance(&sponsors[1]), 966);
+    assert_eq!(token_client.balance(&sponsors[2]), 966);
+    assert_eq!(token_client.balance(&contract_id), 100);
+}
+
+#[test]
+fn test_legacy_lab_reads_as_fully_allocated_until_backfilled() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, contract_id, _, _, token_id, token_client) = setup_treasury(&env);
+    let admin_client = token::StellarAssetClient::new(&env, &token_id);
+
+    let funder = Address::generate(&env);
+    admin_client.mint(&funder, &10_000);
+    let lab_id = client.fund_lab(&funder, &10_000, &1_000);
+
+    // Stored before allocations were tracked
+    env.as_contract(&contract_id, || {
+        let legacy = crate::storage::LabV1 {
+            id: lab_id,
+            funder: funder.clone(),
+            total_amount: 10_000,
+            scholarship_per_member: 1_000,
+            status: LabStatus::Active,
+        };
+        env.storage()
+            .persistent()
+            .set(&crate::storage::DataKey::Lab(lab_id), &legacy);
+    });
+
+    let lab = client.lab(&lab_id).unwrap();
+    assert_eq!(lab.allocated, 10_000);
+    assert_eq!(lab.claimed, 0);
+    assert_eq!(lab.vesting, Vesting::Immediate);
+
+    let student = Address::generate(&env);
+    assert_eq!(
+        client.try_approve_scholarship(&lab_id, &student),
+        Err(Ok(TreasuryError::LabBudgetExceeded))
+    );
+
+    assert_eq!(
+        client.try_backfill_lab(&lab_id, &10_001),
+        Err(Ok(TreasuryError::LabBudgetExceeded))
+    );
+    client.backfill_lab(&lab_id, &3_000);
+    assert_eq!(client.lab(&lab_id).unwrap().allocated, 3_000);
+    assert_eq!(
+        client.try_backfill_lab(&lab_id, &0),
+        Err(Ok(TreasuryError::LabNotLegacy))
+    );
+
+    // Only the escrow not released before the upgrade is refunded
+    assert_eq!(client.cancel_lab(&funder, &lab_id), 7_000);
+    assert_eq!(token_client.balance(&funder), 7_000);
+}
+
+// ============ Scholarship Vesting Tests ============
+
+#[test]
+fn test_linear_vesting() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _, _, _, token_id, token_client) = setup_treasury(&env);
+    let admin_client = token::StellarAssetClient::new(&env, &token_id);
+
+    let funder = Address::generate(&env);
+    admin_client.mint(&funder, &10_000);
+    let lab_id = client.fund_lab_with_vesting(&funder, &10_000, &1_000, &Vesting::Linear(1_000));
+
+    let student = Address::generate(&env);
+    let t0 = env.ledger().timestamp();
+    client.approve_scholarship(&lab_id, &student);
+    assert_eq!(client.vested_amount(&lab_id, &student), 0);
+
+    env.ledger().with_mut(|li| li.timestamp = t0 + 250);
+    assert_eq!(client.vested_amount(&lab_id, &student), 250);
+    assert_eq!(client.claimable_for(&lab_id, &student), 250);
+    assert_eq!(
+        client.try_withdraw_scholarship(&student, &251),
+        Err(Ok(TreasuryError::InsufficientClaimable))
+    );
+    client.withdraw_scholarship(&student, &250);
+    assert_eq!(client.claimable_for(&lab_id, &student), 0);
+
+    env.ledger().with_mut(|li| li.timestamp = t0 + 2_000);
+    assert_eq!(client.vested_amount(&lab_id, &student), 1_000);
+    assert_eq!(client.claimable_for(&lab_id, &student), 750);
+    client.withdraw_scholarship(&student, &750);
+    assert_eq!(token_client.balance(&student), 1_000);
+    assert_eq!(client.lab(&lab_id).unwrap().claimed, 1_000);
+}
+
+#[test]
+fn test_tranche_vesting() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _, _, _, token_id, _) = setup_treasury(&env);
+    let admin_client = token::StellarAssetClient::new(&env, &token_id);
+
+    let funder = Address::generate(&env);
+    admin_client.mint(&funder, &20_000);
+    let tranches = vec![
+        &env,
+        Tranche {
+            unlocks_after: 0,
+            bps: 2_500,
+        },
+        Tranche {
+            unlocks_after: 100,
+            bps: 7_500,
+        },
+    ];
+    let vested_lab =
+        client.fund_lab_with_vesting(&funder, &10_000, &1_000, &Vesting::Tranches(tranches));
+    let immediate_lab = client.fund_lab(&funder, &10_000, &500);
+
+    let student = Address::generate(&env);
+    let t0 = env.ledger().timestamp();
+    client.approve_scholarship(&vested_lab, &student);
+    client.approve_scholarship(&immediate_lab, &student);
+
+    // Balances are kept per lab
+    assert_eq!(client.claimable_for(&vested_lab, &student), 250);
+    assert_eq!(client.claimable_for(&immediate_lab, &student), 500);
+    assert_eq!(client.get_claimable_balance(&student), 750);
+
+    env.ledger().with_mut(|li| li.timestamp = t0 + 100);
+    assert_eq!(client.claimable_for(&vested_lab, &student), 1_000);
+    assert_eq!(client.get_claimable_balance(&student), 1_500);
+}
+
+#[test]
+fn test_clawback_unvested_on_cancellation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, contract_id, _, _, token_id, token_client) = setup_treasury(&env);
+    let admin_client = token::StellarAssetClient::new(&env, &token_id);
+
+    let funder = Address::generate(&env);
+    admin_client.mint(&funder, &10_000);
+    let lab_id = client.fund_lab_with_vesting(&funder, &10_000, &1_000, &Vesting::Linear(1_000));
+
+    let student = Address::generate(&env);
+    let t0 = env.ledger().timestamp();
+    client.approve_scholarship(&lab_id, &student);
+
+    env.ledger().with_mut(|li| li.timestamp = t0 + 250);
+    assert_eq!(
+        client.try_clawback_unvested(&lab_id, &student),
+        Err(Ok(TreasuryError::LabNotCancelled))
+    );
+    assert_eq!(client.cancel_lab(&funder, &lab_id), 9_000);
+
+    // The unvested 750 goes back to the sponsor, the vested 250 stays claimable
+    assert_eq!(client.clawback_unvested(&lab_id, &student), 750);
+    assert_eq!(token_client.balance(&funder), 9_750);
+    assert_eq!(client.lab(&lab_id).unwrap().allocated, 250);
+    assert_eq!(
+        client.try_clawback_unvested(&lab_id, &Address::generate(&env)),
+        Err(Ok(TreasuryError::ScholarshipNotFound))
+    );
+
+    // Vesting stops at the clawback
+    env.ledger().with_mut(|li| li.timestamp = t0 + 2_000);
+    assert_eq!(client.claimable_for(&lab_id, &student), 250);
+    assert_eq!(
+        client.try_clawback_unvested(&lab_id, &student),
+        Err(Ok(TreasuryError::ZeroAmount))
+    );
+    client.withdraw_scholarship(&student, &250);
+    assert_eq!(token_client.balance(&student), 250);
+    assert_eq!(token_client.balance(&contract_id), 0);
+    assert_eq!(client.total_assets(), 0);
+}
+
+#[test]
+fn test_legacy_pooled_balance_is_claimed_first() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, contract_id, _, _, token_id, token_client) = setup_treasury(&env);
+    let admin_client = token::StellarAssetClient::new(&env, &token_id);
+
+    let funder = Address::generate(&env);
+    admin_client.mint(&funder, &10_000);
+    let lab_id = client.fund_lab(&funder, &10_000, &1_000);
+
+    // Released into the pooled balance before scholarships were tracked per lab
+    let student = Address::generate(&env);
+    admin_client.mint(&contract_id, &300);
+    env.as_contract(&contract_id, || {
+        crate::storage::set_claimable(&env, &student, 300);
+        crate::storage::set_restricted_reserves(&env, 10_300);
+    });
+
+    client.approve_scholarship(&lab_id, &student);
+    assert_eq!(client.get_claimable_balance(&student), 1_300);
+
+    client.withdraw_scholarship(&student, &500);
+    assert_eq!(token_client.balance(&student), 500);
+    assert_eq!(client.get_claimable_balance(&student), 800);
+    assert_eq!(client.claimable_for(&lab_id, &student), 800);
+    assert_eq!(client.lab(&lab_id).unwrap().claimed, 200);
+}
+
+#[test]
+fn test_invalid_vesting_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _, _, _, token_id, _) = setup_treasury(&env);
+    let admin_client = token::StellarAssetClient::new(&env, &token_id);
+
+    let funder = Address::generate(&env);
+    admin_client.mint(&funder, &10_000);
+
+    let partial = vec![
+        &env,
+        Tranche {
+            unlocks_after: 0,
+            bps: 5_000,
+        },
+    ];
+    assert_eq!(
+        client.try_fund_lab_with_vesting(&funder, &10_000, &1_000, &Vesting::Tranches(partial)),
+        Err(Ok(TreasuryError::InvalidVesting))
+    );
+    assert_eq!(
+        client.try_fund_lab_with_vesting(&funder, &10_000, &1_000, &Vesting::Linear(0)),
+        Err(Ok(TreasuryError::InvalidVesting))
+    );
+}
+
 // ============ Governance Spending Tests ============
 
 #[test]
@@ -0,0 +1,71 @@
+//! Scholarship vesting schedules.
+//!
+//! A lab's schedule is fixed when it is funded and applies to every scholarship
+//! approved from it, starting at the time of approval.
+
+use crate::storage::Vesting;
+use crate::TreasuryError;
+
+/// Basis points in a whole scholarship.
+pub const TOTAL_BPS: u32 = 10_000;
+
+/// Maximum number of tranches in a schedule.
+pub const MAX_TRANCHES: u32 = 12;
+
+/// Check that a vesting schedule releases exactly the whole scholarship.
+pub fn is_valid(vesting: &Vesting) -> bool {
+    match vesting {
+        Vesting::Immediate => true,
+        Vesting::Linear(duration) => *duration > 0,
+        Vesting::Tranches(tranches) => {
+            if tranches.is_empty() || tranches.len() > MAX_TRANCHES {
+                return false;
+            }
+            let mut total_bps: u32 = 0;
+            let mut last_unlock = 0;
+            for tranche in tranches.iter() {
+                if tranche.bps == 0 || tranche.unlocks_after < last_unlock {
+                    return false;
+                }
+                last_unlock = tranche.unlocks_after;
+                total_bps = total_bps.saturating_add(tranche.bps);
+            }
+            total_bps == TOTAL_BPS
+        }
+    }
+}
+
+/// Portion of `amount` vested at `timestamp` for a scholarship approved at `approved_at`.
+pub fn vested(
+    vesting: &Vesting,
+    amount: i128,
+    approved_at: u64,
+    timestamp: u64,
+) -> Result<i128, TreasuryError> {
+    let elapsed = timestamp.saturating_sub(approved_at);
+    let vested = match vesting {
+        Vesting::Immediate => amount,
+        Vesting::Linear(duration) => {
+            if elapsed >= *duration {
+                amount
+            } else {
+                amount
+                    .checked_mul(i128::from(elapsed))
+                    .ok_or(TreasuryError::MathOverflow)?
+                    / i128::from(*duration)
+            }
+        }
+        Vesting::Tranches(tranches) => {
+            let unlocked_bps: u32 = tranches
+                .iter()
+                .filter(|t| t.unlocks_after <= elapsed)
+                .map(|t| t.bps)
+                .sum();
+            amount
+                .checked_mul(i128::from(unlocked_bps))
+                .ok_or(TreasuryError::MathOverflow)?
+                / i128::from(TOTAL_BPS)
+        }
+    };
+    Ok(vested)
+}
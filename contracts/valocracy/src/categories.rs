@@ -0,0 +1,126 @@
+//! Badge category registry.
+//!
+//! Categories map ranges of valor IDs to a minting policy and a rarity cap.
+//! The registry is seeded with the original taxonomy at initialization and
+//! can be reshaped by governance afterwards. Deployments upgraded from before
+//! the registry existed read the original taxonomy until governance first
+//! changes it.
+
+use soroban_sdk::{vec, Env, String, Vec};
+
+use crate::errors::ValocracyError;
+use crate::storage::{get_category, get_category_ids, set_category, set_category_ids};
+use crate::types::{Category, MintPolicy};
+
+/// Maximum number of categories. Bounds the cost of a valor ID lookup.
+pub const MAX_CATEGORIES: u32 = 32;
+
+fn category(
+    env: &Env,
+    id: u32,
+    name: &str,
+    min_valor_id: u64,
+    max_valor_id: u64,
+    mint_policy: MintPolicy,
+) -> Category {
+    Category {
+        id,
+        name: String::from_str(env, name),
+        min_valor_id,
+        max_valor_id,
+        mint_policy,
+        rarity_cap: u64::MAX,
+    }
+}
+
+/// The original taxonomy: Member, Founder, Leadership, Track, Community, Governance.
+pub fn default_categories(env: &Env) -> Vec<Category> {
+    vec![
+        env,
+        category(env, 0, "Member", 0, 0, MintPolicy::SelfRegister),
+        category(env, 1, "Founder", 1, 1, MintPolicy::Never),
+        category(env, 2, "Leadership", 10, 19, MintPolicy::Governor),
+        category(env, 3, "Track", 20, 59, MintPolicy::HoldersOf(2)),
+        category(env, 4, "Community", 60, 69, MintPolicy::AnyMember),
+        category(env, 5, "Governance", 70, 79, MintPolicy::Governor),
+    ]
+}
+
+/// Store the original taxonomy if the registry was never seeded.
+pub fn seed_defaults(env: &Env) {
+    if get_category_ids(env).is_some() {
+        return;
+    }
+    let mut ids = Vec::new(env);
+    for category in default_categories(env).iter() {
+        set_category(env, &category);
+        ids.push_back(category.id);
+    }
+    set_category_ids(env, &ids);
+}
+
+/// All registered categories, falling back to the original taxonomy.
+pub fn all(env: &Env) -> Vec<Category> {
+    let Some(ids) = get_category_ids(env) else {
+        return default_categories(env);
+    };
+    let mut categories = Vec::new(env);
+    for id in ids.iter() {
+        if let Some(category) = get_category(env, id) {
+            categories.push_back(category);
+        }
+    }
+    categories
+}
+
+/// Get a category by ID, falling back to the original taxonomy.
+pub fn get(env: &Env, category_id: u32) -> Option<Category> {
+    if get_category_ids(env).is_some() {
+        return get_category(env, category_id);
+    }
+    default_categories(env).iter().find(|c| c.id == category_id)
+}
+
+/// Find the category whose range contains `valor_id`.
+pub fn category_of(env: &Env, valor_id: u64) -> Option<Category> {
+    all(env)
+        .iter()
+        .find(|c| c.min_valor_id <= valor_id && valor_id <= c.max_valor_id)
+}
+
+/// Validate and store a category, creating or replacing the one with the same ID.
+pub fn define(env: &Env, category: &Category) -> Result<(), ValocracyError> {
+    if category.min_valor_id > category.max_valor_id {
+        return Err(ValocracyError::InvalidCategory);
+    }
+
+    seed_defaults(env);
+    let mut ids = get_category_ids(env).unwrap_or(Vec::new(env));
+    let is_new = !ids.contains(category.id);
+    if is_new && ids.len() >= MAX_CATEGORIES {
+        return Err(ValocracyError::InvalidCategory);
+    }
+
+    for other in ids.iter().filter(|id| *id != category.id) {
+        if let Some(other) = get_category(env, other) {
+            let overlaps = category.min_valor_id <= other.max_valor_id
+                && other.min_valor_id <= category.max_valor_id;
+            if overlaps {
+                return Err(ValocracyError::InvalidCategory);
+            }
+        }
+    }
+
+    if let MintPolicy::HoldersOf(holder_category) = category.mint_policy {
+        if holder_category != category.id && !ids.contains(holder_category) {
+            return Err(ValocracyError::NonExistentCategory);
+        }
+    }
+
+    set_category(env, category);
+    if is_new {
+        ids.push_back(category.id);
+        set_category_ids(env, &ids);
+    }
+    Ok(())
+}
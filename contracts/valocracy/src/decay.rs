@@ -0,0 +1,104 @@
+//! Mana decay curves.
+//!
+//! A decaying amount is worth its full value when granted and nothing once it
+//! expires, one vacancy period later. The shape in between is selected by the
+//! governance-controlled `DecayConfig`.
+
+use soroban_sdk::Vec;
+
+use crate::types::{DecayConfig, DecayCurve, Grant};
+use crate::VACANCY_PERIOD;
+
+/// Upper bound on the vacancy period: 2 years in seconds.
+/// Keeps the number of expiry buckets scanned for the total supply bounded.
+pub const MAX_VACANCY_PERIOD: u64 = 2 * 365 * 24 * 60 * 60;
+
+/// Check that a decay config describes a usable curve.
+/// A zero floor is rejected: registered accounts must always keep some Mana.
+pub fn is_valid(config: &DecayConfig) -> bool {
+    if config.vacancy_period == 0 || config.vacancy_period > MAX_VACANCY_PERIOD {
+        return false;
+    }
+    if config.floor == 0 {
+        return false;
+    }
+    match config.curve {
+        DecayCurve::Linear | DecayCurve::Step => true,
+        DecayCurve::Exponential => config.half_life > 0,
+        DecayCurve::PlateauLinear => config.plateau < config.vacancy_period,
+    }
+}
+
+/// Portion of `amount` left at `timestamp` for a grant expiring at `expiry`.
+pub fn decayed(config: &DecayConfig, amount: u64, expiry: u64, timestamp: u64) -> u64 {
+    if amount == 0 || timestamp >= expiry {
+        return 0;
+    }
+
+    let period = u128::from(config.vacancy_period);
+    let left = u128::from(expiry - timestamp);
+    // Grants made under a longer period count as freshly minted
+    let remaining = left.min(period);
+    let elapsed = period - remaining;
+    let amount = u128::from(amount);
+
+    // KRN-04: u128 arithmetic prevents overflow with large values
+    let value = match config.curve {
+        // The original period keeps the uncapped formula Mana was first defined with
+        DecayCurve::Linear if config.vacancy_period == VACANCY_PERIOD => amount * left / period,
+        DecayCurve::Linear => amount * remaining / period,
+        DecayCurve::Step => amount,
+        DecayCurve::Exponential => {
+            let half_life = u128::from(config.half_life);
+            let halvings = elapsed / half_life;
+            if halvings >= 64 {
+                0
+            } else {
+                // Interpolate linearly within the current half-life
+                let start = amount >> halvings;
+                start - start * (elapsed % half_life) / (2 * half_life)
+            }
+        }
+        DecayCurve::PlateauLinear => {
+            let plateau = u128::from(config.plateau);
+            if elapsed <= plateau {
+                amount
+            } else {
+                amount * remaining / (period - plateau)
+            }
+        }
+    };
+    // Only the uncapped formula can exceed `amount`
+    u64::try_from(value).unwrap_or(u64::MAX)
+}
+
+/// Decaying bonus and expiry of each grant, oldest first.
+/// The floor is covered by the oldest grants, so only rarity above it decays.
+pub fn bonuses<'a>(
+    config: &'a DecayConfig,
+    grants: &'a Vec<Grant>,
+) -> impl Iterator<Item = (u64, u64)> + 'a {
+    grants.iter().scan(config.floor, |uncovered, grant| {
+        let bonus = grant.rarity.saturating_sub(*uncovered);
+        *uncovered = uncovered.saturating_sub(grant.rarity);
+        Some((bonus, grant.expiry))
+    })
+}
+
+/// Mana of an account holding `grants` at `timestamp`.
+/// Formula: Mana = floor + sum of each grant's decayed bonus, never below the permanent level
+pub fn mana(
+    config: &DecayConfig,
+    permanent_level: u64,
+    grants: &Vec<Grant>,
+    timestamp: u64,
+) -> u64 {
+    // Without grants only the permanent level is left; unregistered accounts have neither
+    if grants.is_empty() {
+        return permanent_level;
+    }
+    let bonus: u64 = bonuses(config, grants)
+        .map(|(bonus, expiry)| decayed(config, bonus, expiry, timestamp))
+        .sum();
+    (config.floor + bonus).max(permanent_level)
+}
@@ -21,4 +21,16 @@ pub enum ValocracyError {
     MintNotAuthorized = 13,
     BadgeNotMintable = 14,
     ReentrancyDetected = 15,
+    SelfDelegation = 16,
+    DelegationChain = 17,
+    NotDelegated = 18,
+    TooManyDelegators = 19,
+    InvalidDecayConfig = 20,
+    InvalidCategory = 21,
+    NonExistentCategory = 22,
+    RarityCapExceeded = 23,
+    SignerNotAuthorized = 24,
+    TooManySigners = 25,
+    InvalidBatch = 26,
+    InvalidAmount = 27,
 }
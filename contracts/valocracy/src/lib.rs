@@ -4,43 +4,65 @@
 #![allow(clippy::too_many_arguments)]
 #![no_std]
 
+mod categories;
+mod decay;
 mod errors;
 mod storage;
+mod supply;
 mod types;
 
 use soroban_sdk::xdr::ToXdr;
 use soroban_sdk::{
-    contract, contractevent, contractimpl, Address, Bytes, BytesN, Env, IntoVal, String, Symbol,
-    Vec,
+    contract, contractevent, contractimpl, vec, Address, Bytes, BytesN, Env, IntoVal, Map, String,
+    Symbol, Vec,
 };
 
 use errors::ValocracyError;
 use storage::{
-    extend_instance_ttl, get_governor, get_member_valor_id, get_signer, get_token_owner,
-    get_token_valor_id, get_total_supply, get_treasury, get_user_stats, get_valor, is_initialized,
-    is_nonce_used, remove_token_owner, remove_token_valor_id, set_governor, set_initialized,
-    set_member_valor_id, set_nonce_used, set_signer, set_token_owner, set_token_valor_id,
-    set_total_supply, set_treasury, set_user_stats, set_valor,
+    extend_instance_ttl, get_checkpoint, get_checkpoint_count, get_decay_config,
+    get_decay_config_checkpoint, get_decay_config_checkpoint_count, get_delegate,
+    get_delegation_checkpoint, get_delegation_checkpoint_count, get_delegators, get_governor,
+    get_grants, get_legacy_expiry, get_member_valor_id, get_revocation, get_signers,
+    get_token_owner, get_token_valor_id, get_total_level, get_total_members, get_total_supply,
+    get_treasury, get_user_stats, get_valor, is_initialized, is_nonce_used, is_permanent_valor,
+    remove_delegate, remove_token_owner, remove_token_valor_id, set_checkpoint,
+    set_checkpoint_count, set_decay_config, set_decay_config_checkpoint,
+    set_decay_config_checkpoint_count, set_delegate, set_delegation_checkpoint,
+    set_delegation_checkpoint_count, set_delegators, set_governor, set_grants, set_initialized,
+    set_legacy_expiry, set_member_valor_id, set_nonce_used, set_permanent_valor, set_revocation,
+    set_signers, set_token_owner, set_token_valor_id, set_total_supply, set_treasury,
+    set_user_stats, set_valor,
 };
-use types::{UserStats, Valor};
+pub use types::{
+    Category, DecayConfig, DecayCurve, Grant, MintPolicy, Revocation, SignedAction, SignedMessage,
+    Signer,
+};
+use types::{Checkpoint, DecayConfigCheckpoint, DelegationCheckpoint, UserStats, Valor};
 
-/// Vacancy period: 180 days in seconds (15,552,000 seconds)
+/// Default vacancy period: 180 days in seconds (15,552,000 seconds)
 pub const VACANCY_PERIOD: u64 = 180 * 24 * 60 * 60;
 
-/// Member Floor: fixed baseline Mana for any registered user.
+/// Default Member Floor: baseline Mana for any registered user.
 /// Matches the Member Badge rarity (id: 0, rarity: 5).
 /// Inactive members decay to MEMBER_FLOOR.
 pub const MEMBER_FLOOR: u64 = 5;
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
-enum BadgeCategory {
-    Member,     // 0
-    Founder,    // 1
-    Leadership, // 10-19
-    Track,      // 20-59
-    Community,  // 60-69
-    Governance, // 70-79
-}
+/// Version of the signed message format. Bumped whenever `SignedMessage` changes.
+pub const SIGNATURE_VERSION: u32 = 1;
+
+/// Maximum number of badges minted by a single `mint_batch` call.
+pub const MAX_BATCH_SIZE: u32 = 50;
+
+/// Maximum number of backend signers.
+pub const MAX_SIGNERS: u32 = 10;
+
+/// Token ID of the grant standing in for the badges of accounts registered
+/// before per-badge grants were recorded. Real token IDs start at 1.
+pub const LEGACY_TOKEN_ID: u64 = 0;
+
+/// Maximum number of accounts that may delegate to a single delegatee.
+/// Bounds the cost of summing delegated Mana.
+pub const MAX_DELEGATORS: u32 = 50;
 
 #[contract]
 pub struct ValocracyContract;
@@ -73,6 +95,39 @@ pub enum ValocracyEvent {
         token_id: u64,
         valor_id: u64,
         new_level: u64,
+        reason: String,
+    },
+    ManaRenewed {
+        #[topic]
+        account: Address,
+        expiry: u64,
+    },
+    PermanentGranted {
+        #[topic]
+        account: Address,
+        amount: u64,
+        permanent_level: u64,
+        reason: String,
+    },
+    PermanentReduced {
+        #[topic]
+        account: Address,
+        amount: u64,
+        permanent_level: u64,
+        reason: String,
+    },
+    ValorPermanenceUpdate {
+        #[topic]
+        valor_id: u64,
+        permanent: bool,
+    },
+    Renounce {
+        #[topic]
+        owner: Address,
+        #[topic]
+        token_id: u64,
+        valor_id: u64,
+        new_level: u64,
     },
     GovernorUpdate {
         new_governor: Address,
@@ -88,6 +143,35 @@ pub enum ValocracyEvent {
     ContractUpgraded {
         new_wasm_hash: BytesN<32>,
     },
+    Delegated {
+        #[topic]
+        delegator: Address,
+        #[topic]
+        delegatee: Address,
+    },
+    Undelegated {
+        #[topic]
+        delegator: Address,
+        #[topic]
+        delegatee: Address,
+    },
+    SignerRotated {
+        #[topic]
+        public_key: BytesN<32>,
+        active: bool,
+    },
+    DecayConfigUpdate {
+        config: DecayConfig,
+    },
+    CategoryUpdate {
+        #[topic]
+        category_id: u32,
+        category: Category,
+    },
+    LegacyMigrated {
+        start_token_id: u64,
+        next_token_id: u64,
+    },
 }
 
 #[contractimpl]
@@ -123,7 +207,17 @@ impl ValocracyContract {
         set_initialized(&env);
         set_governor(&env, &governor);
         set_treasury(&env, &treasury);
-        set_signer(&env, &signer);
+        set_signers(
+            &env,
+            &vec![
+                &env,
+                Signer {
+                    public_key: signer,
+                    allowed_valor_ids: Vec::new(&env),
+                    expires_at: 0,
+                },
+            ],
+        );
         set_member_valor_id(&env, member_valor_id);
         env.storage().instance().set(
             &Symbol::new(&env, "name"),
@@ -135,6 +229,8 @@ impl ValocracyContract {
         );
         set_total_supply(&env, 0);
 
+        categories::seed_defaults(&env);
+
         let count = valor_ids.len();
         for i in 0..count {
             let vid = valor_ids.get(i).unwrap();
@@ -152,6 +248,7 @@ impl ValocracyContract {
         let leadership_rarity = leadership_valor.rarity;
 
         let current_time = env.ledger().timestamp();
+        let vacancy_period = get_decay_config(&env).vacancy_period;
         let mut current_token_id = 1u64;
 
         // Mint leadership badge to all genesis members.
@@ -160,10 +257,18 @@ impl ValocracyContract {
             let member_stats = UserStats {
                 level: leadership_rarity,
                 permanent_level: 0,
-                expiry: current_time + VACANCY_PERIOD,
+                expiry: current_time + vacancy_period,
                 verified: false,
             };
-            set_user_stats(&env, &member, &member_stats);
+            let grant = Grant {
+                token_id: current_token_id,
+                valor_id: leadership_valor_id,
+                rarity: leadership_rarity,
+                minted_at: current_time,
+                expiry: current_time + vacancy_period,
+                permanent: false,
+            };
+            Self::write_stats(&env, &member, &member_stats, &vec![&env, grant]);
 
             set_token_valor_id(&env, current_token_id, leadership_valor_id);
             set_token_owner(&env, current_token_id, &member);
@@ -202,6 +307,10 @@ impl ValocracyContract {
         let governor = get_governor(&env).ok_or(ValocracyError::NotInitialized)?;
         governor.require_auth();
 
+        if categories::category_of(&env, valor_id).is_some_and(|c| rarity > c.rarity_cap) {
+            return Err(ValocracyError::RarityCapExceeded);
+        }
+
         let valor = Valor {
             rarity,
             metadata: metadata.clone(),
@@ -217,6 +326,46 @@ impl ValocracyContract {
         Ok(())
     }
 
+    /// Mark whether badges of a valor add their rarity to the holder's permanent level
+    /// when minted (Governor only). Badges already minted are not affected.
+    pub fn set_valor_permanent(
+        env: Env,
+        valor_id: u64,
+        permanent: bool,
+    ) -> Result<(), ValocracyError> {
+        let governor = get_governor(&env).ok_or(ValocracyError::NotInitialized)?;
+        governor.require_auth();
+
+        get_valor(&env, valor_id).ok_or(ValocracyError::NonExistentValor)?;
+        set_permanent_valor(&env, valor_id, permanent);
+
+        ValocracyEvent::ValorPermanenceUpdate {
+            valor_id,
+            permanent,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Create or replace a badge category (Governor only).
+    /// Ranges may not overlap those of other categories.
+    pub fn set_category(env: Env, category: Category) -> Result<(), ValocracyError> {
+        let governor = get_governor(&env).ok_or(ValocracyError::NotInitialized)?;
+        governor.require_auth();
+
+        categories::define(&env, &category)?;
+
+        extend_instance_ttl(&env);
+
+        ValocracyEvent::CategoryUpdate {
+            category_id: category.id,
+            category,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
     /// Mint a new soulbound NFT. Requires authorization from a valid minter.
     pub fn mint(
         env: Env,
@@ -232,9 +381,58 @@ impl ValocracyContract {
         Self::mint_internal(&env, &recipient, valor_id)
     }
 
+    /// Mint one badge per (recipient, valor_id) pair with the same authorization as `mint`.
+    /// Treasury shares are allocated in a single call. Fails as a whole if any mint fails.
+    pub fn mint_batch(
+        env: Env,
+        minter: Address,
+        recipients: Vec<Address>,
+        valor_ids: Vec<u64>,
+    ) -> Result<Vec<u64>, ValocracyError> {
+        minter.require_auth();
+
+        if recipients.is_empty()
+            || recipients.len() != valor_ids.len()
+            || recipients.len() > MAX_BATCH_SIZE
+        {
+            return Err(ValocracyError::InvalidBatch);
+        }
+
+        let mut token_ids = Vec::new(&env);
+        let mut shares: Map<Address, i128> = Map::new(&env);
+        for (recipient, valor_id) in recipients.iter().zip(valor_ids.iter()) {
+            Self::check_mint_authorization(&env, &minter, valor_id)?;
+
+            let (token_id, rarity) = Self::mint_badge(&env, &recipient, valor_id)?;
+            token_ids.push_back(token_id);
+
+            let held = shares.get(recipient.clone()).unwrap_or(0);
+            shares.set(recipient, held + i128::from(rarity));
+        }
+
+        if let Some(treasury) = get_treasury(&env) {
+            let mut entries = Vec::new(&env);
+            for (recipient, amount) in shares.iter() {
+                if amount > 0 {
+                    entries.push_back((recipient, amount));
+                }
+            }
+            if !entries.is_empty() {
+                let _res: () = env.invoke_contract(
+                    &treasury,
+                    &Symbol::new(&env, "deposit_batch"),
+                    (entries,).into_val(&env),
+                );
+            }
+        }
+
+        Ok(token_ids)
+    }
+
     pub fn self_register(
         env: Env,
         caller: Address,
+        signer: BytesN<32>,
         signature: BytesN<64>,
         nonce: u64,
         expiry: u64,
@@ -246,21 +444,17 @@ impl ValocracyContract {
         }
 
         // Verify signature
-        let mut payload = Bytes::new(&env);
-        payload.append(&caller.clone().to_xdr(&env));
-        payload.append(&nonce.to_xdr(&env));
-        payload.append(&expiry.to_xdr(&env));
+        let action = SignedAction::SelfRegister(caller.clone());
+        Self::verify_signature(&env, &action, &signer, &signature, nonce, expiry)?;
 
-        Self::verify_signature(&env, &payload, &signature, &caller, nonce, expiry)?;
+        // Get the designated member valor_id
+        let member_valor_id = get_member_valor_id(&env).ok_or(ValocracyError::NotInitialized)?;
 
         // Check if user already has stats (already registered)
         if get_user_stats(&env, &caller).is_some() {
             return Err(ValocracyError::AlreadyRegistered);
         }
 
-        // Get the designated member valor_id
-        let member_valor_id = get_member_valor_id(&env).ok_or(ValocracyError::NotInitialized)?;
-
         // Execute minting logic
         Self::mint_internal(&env, &caller, member_valor_id)
     }
@@ -270,6 +464,7 @@ impl ValocracyContract {
         env: Env,
         account: Address,
         valor_id: u64,
+        signer: BytesN<32>,
         signature: BytesN<64>,
         nonce: u64,
         expiry: u64,
@@ -282,53 +477,94 @@ impl ValocracyContract {
         }
 
         // Verify backend signature
-        let mut payload = Bytes::new(&env);
-        payload.append(&account.clone().to_xdr(&env));
-        payload.append(&valor_id.to_xdr(&env));
-        payload.append(&nonce.to_xdr(&env));
-        payload.append(&expiry.to_xdr(&env));
-
         // Note: We use 'account' as the nonce-holder context
-        Self::verify_signature(&env, &payload, &signature, &account, nonce, expiry)?;
+        let action = SignedAction::GuardianMint(account.clone(), valor_id);
+        Self::verify_signature(&env, &action, &signer, &signature, nonce, expiry)?;
 
         // Execute minting logic
         Self::mint_internal(&env, &account, valor_id)
     }
 
+    /// Refresh the decay window of every badge held by `account` using backend signature.
+    /// Records ongoing activity without minting a token. Returns the new expiry.
+    pub fn renew(
+        env: Env,
+        account: Address,
+        signer: BytesN<32>,
+        signature: BytesN<64>,
+        nonce: u64,
+        expiry: u64,
+    ) -> Result<u64, ValocracyError> {
+        account.require_auth();
+
+        if !is_initialized(&env) {
+            return Err(ValocracyError::NotInitialized);
+        }
+
+        // Verify backend signature
+        let action = SignedAction::Renew(account.clone());
+        Self::verify_signature(&env, &action, &signer, &signature, nonce, expiry)?;
+
+        let mut stats = get_user_stats(&env, &account).ok_or(ValocracyError::NonExistentAccount)?;
+        Self::enroll_legacy(&env, &account);
+
+        let new_expiry = env.ledger().timestamp() + get_decay_config(&env).vacancy_period;
+        let stored = get_grants(&env, &account);
+        if Self::legacy_level(&stats, &stored) > 0 {
+            let legacy_expiry = Self::legacy_expiry(&env, &account, &stats);
+            set_legacy_expiry(&env, &account, legacy_expiry.max(new_expiry));
+        }
+        let mut grants = Vec::new(&env);
+        for mut grant in stored.iter() {
+            grant.expiry = grant.expiry.max(new_expiry);
+            grants.push_back(grant);
+        }
+        stats.expiry = stats.expiry.max(new_expiry);
+        Self::write_stats(&env, &account, &stats, &grants);
+
+        extend_instance_ttl(&env);
+
+        ValocracyEvent::ManaRenewed {
+            account,
+            expiry: stats.expiry,
+        }
+        .publish(&env);
+
+        Ok(stats.expiry)
+    }
+
     /// Revoke a badge token (Governor only).
-    pub fn revoke(env: Env, token_id: u64) -> Result<(), ValocracyError> {
+    /// The reason is kept with the revocation record for auditing.
+    pub fn revoke(env: Env, token_id: u64, reason: String) -> Result<(), ValocracyError> {
         let governor = get_governor(&env).ok_or(ValocracyError::NotInitialized)?;
         governor.require_auth();
 
-        // Get token owner
-        let owner = get_token_owner(&env, token_id).ok_or(ValocracyError::NonExistentToken)?;
+        let (owner, valor_id, new_level) = Self::remove_token(&env, token_id, &reason, false)?;
 
-        // Get valor rarity
-        let valor_id =
-            get_token_valor_id(&env, token_id).ok_or(ValocracyError::NonExistentToken)?;
-        let valor = get_valor(&env, valor_id).ok_or(ValocracyError::NonExistentValor)?;
-        let rarity = valor.rarity;
+        ValocracyEvent::Revoke {
+            owner,
+            token_id,
+            valor_id,
+            new_level,
+            reason,
+        }
+        .publish(&env);
 
-        let current_stats =
-            get_user_stats(&env, &owner).ok_or(ValocracyError::NonExistentAccount)?;
-        let new_level = current_stats.level.saturating_sub(rarity);
-        let new_permanent = current_stats.permanent_level.saturating_sub(rarity);
+        Ok(())
+    }
 
-        let new_stats = UserStats {
-            level: new_level,
-            permanent_level: new_permanent,
-            expiry: current_stats.expiry,
-            verified: current_stats.verified,
-        };
-        set_user_stats(&env, &owner, &new_stats);
+    /// Give up a badge held by `owner`.
+    pub fn renounce(env: Env, owner: Address, token_id: u64) -> Result<(), ValocracyError> {
+        owner.require_auth();
 
-        // Remove token entries
-        remove_token_owner(&env, token_id);
-        remove_token_valor_id(&env, token_id);
+        if get_token_owner(&env, token_id).as_ref() != Some(&owner) {
+            return Err(ValocracyError::NotAuthorized);
+        }
 
-        extend_instance_ttl(&env);
+        let reason = String::from_str(&env, "");
+        let (owner, valor_id, new_level) = Self::remove_token(&env, token_id, &reason, true)?;
 
-        ValocracyEvent::Revoke {
+        ValocracyEvent::Renounce {
             owner,
             token_id,
             valor_id,
@@ -339,6 +575,139 @@ impl ValocracyContract {
         Ok(())
     }
 
+    /// Get the revocation record of a token that was revoked or renounced
+    pub fn revocation_of(env: Env, token_id: u64) -> Option<Revocation> {
+        get_revocation(&env, token_id)
+    }
+
+    /// Raise the permanent level of a member (Governor only).
+    /// Mana never decays below the permanent level.
+    pub fn grant_permanent(
+        env: Env,
+        account: Address,
+        amount: u64,
+        reason: String,
+    ) -> Result<u64, ValocracyError> {
+        let governor = get_governor(&env).ok_or(ValocracyError::NotInitialized)?;
+        governor.require_auth();
+
+        if amount == 0 {
+            return Err(ValocracyError::InvalidAmount);
+        }
+
+        let mut stats = get_user_stats(&env, &account)
+            .filter(|s| s.level > 0)
+            .ok_or(ValocracyError::NonExistentAccount)?;
+        stats.permanent_level = stats
+            .permanent_level
+            .checked_add(amount)
+            .ok_or(ValocracyError::InvalidAmount)?;
+        Self::write_stats(&env, &account, &stats, &get_grants(&env, &account));
+
+        ValocracyEvent::PermanentGranted {
+            account,
+            amount,
+            permanent_level: stats.permanent_level,
+            reason,
+        }
+        .publish(&env);
+
+        Ok(stats.permanent_level)
+    }
+
+    /// Lower the permanent level of a member (Governor only).
+    pub fn reduce_permanent(
+        env: Env,
+        account: Address,
+        amount: u64,
+        reason: String,
+    ) -> Result<u64, ValocracyError> {
+        let governor = get_governor(&env).ok_or(ValocracyError::NotInitialized)?;
+        governor.require_auth();
+
+        let mut stats = get_user_stats(&env, &account).ok_or(ValocracyError::NonExistentAccount)?;
+        if amount == 0 || amount > stats.permanent_level {
+            return Err(ValocracyError::InvalidAmount);
+        }
+        stats.permanent_level -= amount;
+        Self::write_stats(&env, &account, &stats, &get_grants(&env, &account));
+
+        ValocracyEvent::PermanentReduced {
+            account,
+            amount,
+            permanent_level: stats.permanent_level,
+            reason,
+        }
+        .publish(&env);
+
+        Ok(stats.permanent_level)
+    }
+
+    /// Add a backend signer or replace the one with the same public key (Governor only).
+    pub fn set_signer(env: Env, signer: Signer) -> Result<(), ValocracyError> {
+        let governor = get_governor(&env).ok_or(ValocracyError::NotInitialized)?;
+        governor.require_auth();
+
+        let mut signers = get_signers(&env);
+        match signers
+            .iter()
+            .position(|s| s.public_key == signer.public_key)
+        {
+            Some(index) => signers.set(index as u32, signer.clone()),
+            None => {
+                if signers.len() >= MAX_SIGNERS {
+                    return Err(ValocracyError::TooManySigners);
+                }
+                signers.push_back(signer.clone());
+            }
+        }
+        set_signers(&env, &signers);
+
+        extend_instance_ttl(&env);
+
+        ValocracyEvent::SignerRotated {
+            public_key: signer.public_key,
+            active: true,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Remove a backend signer (Governor only).
+    pub fn remove_signer(env: Env, public_key: BytesN<32>) -> Result<(), ValocracyError> {
+        let governor = get_governor(&env).ok_or(ValocracyError::NotInitialized)?;
+        governor.require_auth();
+
+        let mut signers = get_signers(&env);
+        let index = signers
+            .iter()
+            .position(|s| s.public_key == public_key)
+            .ok_or(ValocracyError::SignerNotAuthorized)?;
+        signers.remove(index as u32);
+        set_signers(&env, &signers);
+
+        extend_instance_ttl(&env);
+
+        ValocracyEvent::SignerRotated {
+            public_key,
+            active: false,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Get the exact bytes a backend signer must sign to authorize `action`
+    pub fn signing_payload(env: Env, action: SignedAction, nonce: u64, expiry: u64) -> Bytes {
+        Self::signed_payload(&env, &action, nonce, expiry)
+    }
+
+    /// Get the backend signers
+    pub fn signers(env: Env) -> Vec<Signer> {
+        get_signers(&env)
+    }
+
     /// Update the governor contract address (Governor only).
     pub fn update_governor(env: Env, new_governor: Address) -> Result<(), ValocracyError> {
         let governor = get_governor(&env).ok_or(ValocracyError::NotInitialized)?;
@@ -370,6 +739,72 @@ impl ValocracyContract {
         Ok(())
     }
 
+    /// Backfill per-badge grants for badges minted before they were recorded (Governor only).
+    /// Processes up to `limit` token IDs from `start_token_id` and returns the next ID to migrate.
+    /// Mana is unchanged; migrated badges show up in `tokens_of` and the badge queries,
+    /// and their owners are added to the total supply.
+    pub fn migrate(env: Env, start_token_id: u64, limit: u32) -> Result<u64, ValocracyError> {
+        let governor = get_governor(&env).ok_or(ValocracyError::NotInitialized)?;
+        governor.require_auth();
+
+        if limit == 0 || limit > MAX_BATCH_SIZE {
+            return Err(ValocracyError::InvalidBatch);
+        }
+
+        let start = start_token_id.max(1);
+        let end = start
+            .saturating_add(u64::from(limit))
+            .min(get_total_supply(&env) + 1);
+        for token_id in start..end {
+            // Revoked and renounced tokens no longer have an owner
+            let Some(owner) = get_token_owner(&env, token_id) else {
+                continue;
+            };
+            let Some(stats) = get_user_stats(&env, &owner) else {
+                continue;
+            };
+            Self::enroll_legacy(&env, &owner);
+            let mut grants = get_grants(&env, &owner);
+            if grants.iter().any(|g| g.token_id == token_id) {
+                continue;
+            }
+            let legacy_level = Self::legacy_level(&stats, &grants);
+            let Some(valor_id) = get_token_valor_id(&env, token_id) else {
+                continue;
+            };
+            let rarity = get_valor(&env, valor_id).map_or(0, |v| v.rarity.min(legacy_level));
+            if rarity == 0 {
+                continue;
+            }
+
+            let expiry = Self::legacy_expiry(&env, &owner, &stats);
+            let grant = Grant {
+                token_id,
+                valor_id,
+                rarity,
+                minted_at: expiry.saturating_sub(get_decay_config(&env).vacancy_period),
+                expiry,
+                permanent: false,
+            };
+            // Keep grants in mint order so the floor stays covered by the oldest badges
+            match grants.iter().position(|g| g.token_id > token_id) {
+                Some(index) => grants.insert(index as u32, grant),
+                None => grants.push_back(grant),
+            }
+            set_grants(&env, &owner, &grants);
+        }
+
+        extend_instance_ttl(&env);
+
+        ValocracyEvent::LegacyMigrated {
+            start_token_id,
+            next_token_id: end.max(start),
+        }
+        .publish(&env);
+
+        Ok(end.max(start))
+    }
+
     /// Set the verification status of a member (Governor only).
     /// Unverified members cannot withdraw funds from the treasury.
     pub fn set_verified(env: Env, member: Address, verified: bool) -> Result<(), ValocracyError> {
@@ -390,6 +825,129 @@ impl ValocracyContract {
         Ok(())
     }
 
+    /// Update the Mana decay parameters (Governor only).
+    /// Existing badges keep their expiry; the new curve and floor apply from now on.
+    pub fn set_decay_config(env: Env, config: DecayConfig) -> Result<(), ValocracyError> {
+        let governor = get_governor(&env).ok_or(ValocracyError::NotInitialized)?;
+        governor.require_auth();
+
+        if !decay::is_valid(&config) {
+            return Err(ValocracyError::InvalidDecayConfig);
+        }
+
+        let previous = get_decay_config(&env);
+        set_decay_config(&env, &config);
+        Self::write_decay_config(&env, &previous, &config);
+        supply::record_config_change(&env, &previous);
+
+        extend_instance_ttl(&env);
+
+        ValocracyEvent::DecayConfigUpdate { config }.publish(&env);
+
+        Ok(())
+    }
+
+    /// Delegate voting weight to another member.
+    /// Badges stay soulbound; only the delegator's Mana is counted for the delegatee.
+    /// Delegation is not transitive: delegatees cannot delegate and delegators cannot receive.
+    pub fn delegate(
+        env: Env,
+        delegator: Address,
+        delegatee: Address,
+    ) -> Result<(), ValocracyError> {
+        delegator.require_auth();
+
+        if delegator == delegatee {
+            return Err(ValocracyError::SelfDelegation);
+        }
+
+        if get_user_stats(&env, &delegator).is_none() || get_user_stats(&env, &delegatee).is_none()
+        {
+            return Err(ValocracyError::NonExistentAccount);
+        }
+
+        if get_delegate(&env, &delegatee).is_some() || !get_delegators(&env, &delegator).is_empty()
+        {
+            return Err(ValocracyError::DelegationChain);
+        }
+
+        let previous = get_delegate(&env, &delegator);
+        Self::seed_delegation_history(&env, &delegator);
+        Self::seed_delegation_history(&env, &delegatee);
+        if let Some(previous) = &previous {
+            Self::seed_delegation_history(&env, previous);
+            Self::remove_delegator(&env, previous, &delegator);
+        }
+
+        let mut delegators = get_delegators(&env, &delegatee);
+        if delegators.len() >= MAX_DELEGATORS {
+            return Err(ValocracyError::TooManyDelegators);
+        }
+        delegators.push_back(delegator.clone());
+        set_delegators(&env, &delegatee, &delegators);
+        set_delegate(&env, &delegator, &delegatee);
+
+        Self::write_delegation(&env, &delegator);
+        Self::write_delegation(&env, &delegatee);
+        if let Some(previous) = previous.filter(|p| *p != delegatee) {
+            Self::write_delegation(&env, &previous);
+        }
+
+        extend_instance_ttl(&env);
+
+        ValocracyEvent::Delegated {
+            delegator,
+            delegatee,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Remove an existing delegation, restoring the delegator's own vote.
+    pub fn undelegate(env: Env, delegator: Address) -> Result<(), ValocracyError> {
+        delegator.require_auth();
+
+        let delegatee = get_delegate(&env, &delegator).ok_or(ValocracyError::NotDelegated)?;
+
+        Self::seed_delegation_history(&env, &delegator);
+        Self::seed_delegation_history(&env, &delegatee);
+        Self::remove_delegator(&env, &delegatee, &delegator);
+        remove_delegate(&env, &delegator);
+        Self::write_delegation(&env, &delegator);
+        Self::write_delegation(&env, &delegatee);
+
+        extend_instance_ttl(&env);
+
+        ValocracyEvent::Undelegated {
+            delegator,
+            delegatee,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Get the account `account` currently delegates its voting weight to
+    pub fn delegates_of(env: Env, account: Address) -> Option<Address> {
+        get_delegate(&env, &account)
+    }
+
+    /// Get the accounts currently delegating to `account`
+    pub fn delegators_of(env: Env, account: Address) -> Vec<Address> {
+        get_delegators(&env, &account)
+    }
+
+    /// Get the account `account` delegated its voting weight to at `timestamp`
+    pub fn delegates_at(env: Env, account: Address, timestamp: u64) -> Option<Address> {
+        Self::delegation_at(&env, &account, timestamp).delegate
+    }
+
+    /// Get the accounts delegating to `account` at `timestamp`
+    pub fn delegators_at(env: Env, account: Address, timestamp: u64) -> Vec<Address> {
+        Self::delegation_at(&env, &account, timestamp).delegators
+    }
+
     /// Get the contract name
     pub fn name(env: Env) -> String {
         env.storage()
@@ -411,9 +969,19 @@ impl ValocracyContract {
         get_total_supply(&env)
     }
 
-    /// Get the vacancy period (180 days in seconds)
-    pub fn vacancy_period(_env: Env) -> u64 {
-        VACANCY_PERIOD
+    /// Get the vacancy period in seconds
+    pub fn vacancy_period(env: Env) -> u64 {
+        get_decay_config(&env).vacancy_period
+    }
+
+    /// Get the Mana decay parameters
+    pub fn decay_config(env: Env) -> DecayConfig {
+        get_decay_config(&env)
+    }
+
+    /// Get the Mana decay parameters in effect at `timestamp`
+    pub fn decay_config_at(env: Env, timestamp: u64) -> DecayConfig {
+        Self::config_at(&env, timestamp)
     }
 
     /// Get the governor contract address
@@ -446,17 +1014,79 @@ impl ValocracyContract {
         get_token_owner(&env, token_id)
     }
 
+    /// Get the token IDs held by an account, oldest first
+    pub fn tokens_of(env: Env, account: Address) -> Vec<u64> {
+        let grants = get_grants(&env, &account);
+        Self::tokens_of_paginated(env, account, 0, grants.len())
+    }
+
+    /// Get up to `limit` token IDs held by an account, starting at index `start`
+    pub fn tokens_of_paginated(env: Env, account: Address, start: u32, limit: u32) -> Vec<u64> {
+        let grants = get_grants(&env, &account);
+        let end = start.saturating_add(limit).min(grants.len());
+        let mut tokens = Vec::new(&env);
+        for index in start..end {
+            tokens.push_back(grants.get_unchecked(index).token_id);
+        }
+        tokens
+    }
+
+    /// Get the number of badges held by an account
+    pub fn balance_of(env: Env, account: Address) -> u32 {
+        get_grants(&env, &account).len()
+    }
+
+    /// Check whether an account holds at least one badge of a valor type
+    pub fn has_badge(env: Env, account: Address, valor_id: u64) -> bool {
+        get_grants(&env, &account)
+            .iter()
+            .any(|g| g.valor_id == valor_id)
+    }
+
+    /// Count the badges of a category held by an account
+    pub fn count_badges(env: Env, account: Address, category_id: u32) -> u32 {
+        Self::count_category(&env, &account, category_id)
+    }
+
+    /// Get a badge category by ID
+    pub fn category(env: Env, category_id: u32) -> Option<Category> {
+        categories::get(&env, category_id)
+    }
+
+    /// Get all badge categories
+    pub fn categories(env: Env) -> Vec<Category> {
+        categories::all(&env)
+    }
+
+    /// Get the category a valor ID belongs to
+    pub fn category_of(env: Env, valor_id: u64) -> Option<Category> {
+        categories::category_of(&env, valor_id)
+    }
+
     /// Get the raw level of an account (without decay)
     pub fn level_of(env: Env, account: Address) -> u64 {
         get_user_stats(&env, &account).map_or(0, |s| s.level)
     }
 
+    /// Check whether badges of a valor add to the holder's permanent level
+    pub fn is_permanent_valor(env: Env, valor_id: u64) -> bool {
+        is_permanent_valor(&env, valor_id)
+    }
+
     /// Get the permanent level of an account
     pub fn permanent_level_of(env: Env, account: Address) -> u64 {
         get_user_stats(&env, &account).map_or(0, |s| s.permanent_level)
     }
 
-    /// Get the expiry timestamp of an account
+    /// Get the badge held as `token_id` with its mint time and expiry
+    pub fn grant_of(env: Env, token_id: u64) -> Option<Grant> {
+        let owner = get_token_owner(&env, token_id)?;
+        get_grants(&env, &owner)
+            .iter()
+            .find(|g| g.token_id == token_id)
+    }
+
+    /// Get the latest badge expiry timestamp of an account
     pub fn expiry_of(env: Env, account: Address) -> u64 {
         let stats = get_user_stats(&env, &account);
         let expiry = stats.map_or(0, |s| s.expiry);
@@ -471,7 +1101,7 @@ impl ValocracyContract {
     }
 
     /// Get the current voting power (Mana) of an account.
-    /// Registered accounts retain at least MEMBER_FLOOR.
+    /// Registered accounts retain at least the configured floor.
     pub fn get_votes(env: Env, account: Address) -> u64 {
         let stats = match get_user_stats(&env, &account) {
             Some(s) => s,
@@ -479,58 +1109,77 @@ impl ValocracyContract {
         };
 
         let current_time = env.ledger().timestamp();
-        Self::calculate_mana(
-            stats.level,
+        decay::mana(
+            &get_decay_config(&env),
             stats.permanent_level,
-            stats.expiry,
+            &Self::mana_grants(&env, &account, &stats, &get_grants(&env, &account)),
             current_time,
         )
     }
 
     /// Get voting power (Mana) at a specific timestamp (KRN-02).
+    ///
+    /// Uses the stats checkpoint and decay config in effect at `timestamp`, so
+    /// badges minted or revoked and config changes made afterwards do not
+    /// change the result.
     pub fn get_votes_at(env: Env, account: Address, timestamp: u64) -> u64 {
-        let stats = match get_user_stats(&env, &account) {
-            Some(s) => s,
-            None => return 0,
-        };
+        let config = Self::config_at(&env, timestamp);
+        if get_checkpoint_count(&env, &account) == 0 {
+            // Accounts registered before checkpoints existed fall back to current stats
+            return match get_user_stats(&env, &account) {
+                Some(stats) => decay::mana(
+                    &config,
+                    stats.permanent_level,
+                    &Self::mana_grants(&env, &account, &stats, &get_grants(&env, &account)),
+                    timestamp,
+                ),
+                None => 0,
+            };
+        }
 
-        Self::calculate_mana(stats.level, stats.permanent_level, stats.expiry, timestamp)
+        match Self::checkpoint_at(&env, &account, timestamp) {
+            Some(cp) => decay::mana(&config, cp.permanent_level, &cp.grants, timestamp),
+            None => 0,
+        }
     }
 
-    /// Calculate Mana with decay.
-    /// Formula: Mana = floor + (extra_level * time_remaining) / VACANCY_PERIOD
-    fn calculate_mana(level: u64, permanent_level: u64, expiry: u64, current_time: u64) -> u64 {
-        if level == 0 {
-            return 0;
-        }
+    /// Get the number of stats checkpoints recorded for an account
+    pub fn num_checkpoints(env: Env, account: Address) -> u32 {
+        get_checkpoint_count(&env, &account)
+    }
 
-        let floor = MEMBER_FLOOR;
-        if current_time >= expiry {
-            // Return max(permanent, floor)
-            return if permanent_level > floor {
-                permanent_level
-            } else {
-                floor
-            };
-        }
+    /// Get a stats checkpoint of an account by index
+    pub fn checkpoint_of(env: Env, account: Address, index: u32) -> Option<Checkpoint> {
+        get_checkpoint(&env, &account, index)
+    }
 
-        let extra_level = level.saturating_sub(floor);
+    /// Get current voting power including delegated Mana.
+    /// Returns 0 for accounts that have delegated their own weight away.
+    pub fn get_voting_power(env: Env, account: Address) -> u64 {
+        let timestamp = env.ledger().timestamp();
+        Self::get_voting_power_at(env, account, timestamp)
+    }
 
-        let bonus = {
-            let time_remaining = expiry - current_time;
-            // KRN-04 FIX: Cast to u128 to prevent overflow with large values
-            let result =
-                (u128::from(extra_level) * u128::from(time_remaining)) / u128::from(VACANCY_PERIOD);
-            // Safe to cast back since result <= extra_level (division by period)
-            result as u64
-        };
+    /// Get voting power including delegated Mana at a specific timestamp.
+    ///
+    /// Uses the delegation checkpoint in effect at `timestamp`, so delegations
+    /// made afterwards do not move weight into or out of the snapshot.
+    pub fn get_voting_power_at(env: Env, account: Address, timestamp: u64) -> u64 {
+        let delegation = Self::delegation_at(&env, &account, timestamp);
+        if delegation.delegate.is_some() {
+            return 0;
+        }
 
-        floor + bonus
+        let mut total = Self::get_votes_at(env.clone(), account.clone(), timestamp);
+        for delegator in delegation.delegators.iter() {
+            total += Self::get_votes_at(env.clone(), delegator, timestamp);
+        }
+        total
     }
 
     /// Check if account has any active voting power
     ///
-    /// Any registered account (level > 0) always has at least MEMBER_FLOOR voting power.
+    /// Any registered account (level > 0) always has at least the floor voting power.
     pub fn has_voting_power(env: Env, account: Address) -> bool {
         Self::level_of(env, account) > 0
     }
@@ -542,69 +1191,54 @@ impl ValocracyContract {
         get_user_stats(&env, &account).is_some_and(|s| s.verified)
     }
 
-    /// Get conservative lower bound of total Mana (KRN-03).
+    /// Get the current total Mana of all registered accounts (KRN-03).
     /// Used for participation threshold.
     pub fn total_mana(env: Env) -> u64 {
-        let total_supply = get_total_supply(&env);
-        total_supply * MEMBER_FLOOR
+        supply::total_mana_at(&env, env.ledger().timestamp())
     }
 
-    /// Get the category of a badge based on its ID
-    fn get_badge_category(valor_id: u64) -> BadgeCategory {
-        match valor_id {
-            0 => BadgeCategory::Member,
-            1 => BadgeCategory::Founder,
-            10..=19 => BadgeCategory::Leadership,
-            20..=59 => BadgeCategory::Track,
-            60..=69 => BadgeCategory::Community,
-            70..=79 => BadgeCategory::Governance,
-            _ => panic!("Invalid valor_id"), // Should never happen after validation
-        }
+    /// Get the total Mana of all registered accounts at a specific timestamp.
+    pub fn total_mana_at(env: Env, timestamp: u64) -> u64 {
+        supply::total_mana_at(&env, timestamp)
     }
 
-    /// Check if minter is authorized (RBAC).
+    /// Get the number of accounts with a level above zero
+    pub fn total_members(env: Env) -> u64 {
+        get_total_members(&env)
+    }
+
+    /// Get the sum of all account levels (without decay)
+    pub fn total_level(env: Env) -> u64 {
+        get_total_level(&env)
+    }
+
+    /// Check if minter is authorized by the category's minting policy (RBAC).
     fn check_mint_authorization(
         env: &Env,
         minter: &Address,
         valor_id: u64,
     ) -> Result<(), ValocracyError> {
-        let category = Self::get_badge_category(valor_id);
+        let category =
+            categories::category_of(env, valor_id).ok_or(ValocracyError::InvalidValorId)?;
         let governor = get_governor(env).ok_or(ValocracyError::NotInitialized)?;
 
-        match category {
-            BadgeCategory::Member => {
-                // Can only be minted via self_register
+        match category.mint_policy {
+            MintPolicy::SelfRegister | MintPolicy::Never => {
+                // Member badges come from self_register; Never is closed after initialization
                 return Err(ValocracyError::BadgeNotMintable);
             }
-            BadgeCategory::Founder => {
-                // Never mintable after initialization
-                return Err(ValocracyError::BadgeNotMintable);
-            }
-            BadgeCategory::Governance | BadgeCategory::Leadership => {
-                // Governor-only
+            MintPolicy::Governor => {
                 if minter != &governor {
                     return Err(ValocracyError::MintNotAuthorized);
                 }
             }
-            BadgeCategory::Track => {
-                // Governor OR leadership holders
-                if minter != &governor {
-                    let minter_stats = get_user_stats(env, minter);
-                    let has_leadership = minter_stats
-                        .map(|s| s.level >= 10) // Leadership badges have rarity >= 10
-                        .unwrap_or(false);
-
-                    if !has_leadership {
-                        return Err(ValocracyError::MintNotAuthorized);
-                    }
+            MintPolicy::HoldersOf(holder_category) => {
+                if minter != &governor && Self::count_category(env, minter, holder_category) == 0 {
+                    return Err(ValocracyError::MintNotAuthorized);
                 }
             }
-            BadgeCategory::Community => {
-                // Any member (level > 0)
-                let minter_stats = get_user_stats(env, minter);
-                let is_member = minter_stats.map(|s| s.level > 0).unwrap_or(false);
-
-                if !is_member {
+            MintPolicy::AnyMember => {
+                if get_grants(env, minter).is_empty() {
                     return Err(ValocracyError::MintNotAuthorized);
                 }
             }
@@ -613,31 +1247,6 @@ impl ValocracyContract {
         Ok(())
     }
 
-    fn validate_badge_id(valor_id: u64) -> Result<(), ValocracyError> {
-        // Categories:
-        // Member: 0
-        // Founder: 1
-        // Leadership: 10-19
-        // Track: 20-59
-        // Community: 60-69
-        // Governance: 70-79
-
-        if valor_id <= 1 {
-            return Ok(()); // Member (0) or Founder (1)
-        }
-        if (10..=79).contains(&valor_id) {
-            return Ok(());
-        }
-
-        // Future proofing: allow higher IDs but maybe restrict them?
-        // For now, let's strictly enforce the spec ranges to prevent
-        // accidental minting of "test" or "garbage" IDs.
-        // If we want to allow arbitrary IDs later, we can remove this or expand it.
-        // But SC-001.5 says "Validate that badge_id matches expected categories".
-
-        Err(ValocracyError::InvalidValorId)
-    }
-
     /// Check that the caller is a member (has level > 0).
     #[allow(dead_code)]
     fn require_member(env: &Env, caller: &Address) -> Result<(), ValocracyError> {
@@ -653,15 +1262,409 @@ impl ValocracyContract {
 }
 
 impl ValocracyContract {
+    /// Persist new stats and grants and record a checkpoint for historical Mana lookups.
+    fn write_stats(env: &Env, account: &Address, stats: &UserStats, grants: &Vec<Grant>) {
+        Self::enroll_legacy(env, account);
+        let previous = get_user_stats(env, account);
+        let previous_grants = match previous.as_ref() {
+            Some(prev) => Self::mana_grants(env, account, prev, &get_grants(env, account)),
+            None => Vec::new(env),
+        };
+        let counted = Self::mana_grants(env, account, stats, grants);
+        supply::record_change(
+            env,
+            account,
+            previous.as_ref(),
+            &previous_grants,
+            stats,
+            &counted,
+        );
+        set_user_stats(env, account, stats);
+        set_grants(env, account, grants);
+
+        let checkpoint = Checkpoint {
+            timestamp: env.ledger().timestamp(),
+            level: stats.level,
+            permanent_level: stats.permanent_level,
+            expiry: stats.expiry,
+            grants: counted,
+        };
+
+        let count = get_checkpoint_count(env, account);
+        if count > 0 {
+            // Several changes in the same ledger collapse into one checkpoint
+            let last = get_checkpoint(env, account, count - 1);
+            if last.is_some_and(|cp| cp.timestamp == checkpoint.timestamp) {
+                set_checkpoint(env, account, count - 1, &checkpoint);
+                return;
+            }
+        }
+        set_checkpoint(env, account, count, &checkpoint);
+        set_checkpoint_count(env, account, count + 1);
+    }
+
+    /// Bring an account registered before checkpoints and supply tracking up to date.
+    ///
+    /// Freezes the expiry of its legacy badges, records its current state as
+    /// its first checkpoint and adds it to the total supply. No-op for accounts
+    /// that already have checkpoints.
+    fn enroll_legacy(env: &Env, account: &Address) {
+        let Some(stats) = get_user_stats(env, account) else {
+            return;
+        };
+        if get_checkpoint_count(env, account) > 0 {
+            return;
+        }
+        let stored = get_grants(env, account);
+        if Self::legacy_level(&stats, &stored) > 0 && get_legacy_expiry(env, account).is_none() {
+            set_legacy_expiry(env, account, stats.expiry);
+        }
+        let grants = Self::mana_grants(env, account, &stats, &stored);
+        supply::record_enrollment(env, account, &stats, &grants);
+
+        let initial = Checkpoint {
+            timestamp: 0,
+            level: stats.level,
+            permanent_level: stats.permanent_level,
+            expiry: stats.expiry,
+            grants,
+        };
+        set_checkpoint(env, account, 0, &initial);
+        set_checkpoint_count(env, account, 1);
+    }
+
+    /// Level of the badges an account held before per-badge grants were recorded.
+    fn legacy_level(stats: &UserStats, grants: &Vec<Grant>) -> u64 {
+        let recorded: u64 = grants.iter().map(|g| g.rarity).sum();
+        stats.level.saturating_sub(recorded)
+    }
+
+    /// Expiry of the badges an account held before per-badge grants were recorded.
+    fn legacy_expiry(env: &Env, account: &Address, stats: &UserStats) -> u64 {
+        get_legacy_expiry(env, account).unwrap_or(stats.expiry)
+    }
+
+    /// Grants counted towards the Mana of an account.
+    ///
+    /// Badges minted before per-badge grants were recorded only add to the
+    /// level. Until `migrate` backfills them they are counted as a single
+    /// grant, oldest first, that is never written to the account's grants.
+    fn mana_grants(
+        env: &Env,
+        account: &Address,
+        stats: &UserStats,
+        grants: &Vec<Grant>,
+    ) -> Vec<Grant> {
+        let rarity = Self::legacy_level(stats, grants);
+        if rarity == 0 {
+            return grants.clone();
+        }
+        let expiry = Self::legacy_expiry(env, account, stats);
+        let mut counted = vec![
+            env,
+            Grant {
+                token_id: LEGACY_TOKEN_ID,
+                valor_id: 0,
+                rarity,
+                minted_at: expiry.saturating_sub(get_decay_config(env).vacancy_period),
+                expiry,
+                permanent: false,
+            },
+        ];
+        counted.append(grants);
+        counted
+    }
+
+    /// Binary search for the latest checkpoint at or before `timestamp`.
+    fn checkpoint_at(env: &Env, account: &Address, timestamp: u64) -> Option<Checkpoint> {
+        let mut low = 0u32;
+        let mut high = get_checkpoint_count(env, account);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let cp = get_checkpoint(env, account, mid)?;
+            if cp.timestamp > timestamp {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+        if low == 0 {
+            return None;
+        }
+        get_checkpoint(env, account, low - 1)
+    }
+
+    /// Record a decay config change for historical Mana lookups.
+    /// The first change also records the config that was in effect before it.
+    fn write_decay_config(env: &Env, previous: &DecayConfig, config: &DecayConfig) {
+        let mut count = get_decay_config_checkpoint_count(env);
+        if count == 0 {
+            let initial = DecayConfigCheckpoint {
+                timestamp: 0,
+                config: previous.clone(),
+            };
+            set_decay_config_checkpoint(env, 0, &initial);
+            count = 1;
+        }
+
+        let checkpoint = DecayConfigCheckpoint {
+            timestamp: env.ledger().timestamp(),
+            config: config.clone(),
+        };
+        // Several changes in the same ledger collapse into one checkpoint
+        let last = get_decay_config_checkpoint(env, count - 1);
+        if last.is_some_and(|cp| cp.timestamp == checkpoint.timestamp) {
+            set_decay_config_checkpoint(env, count - 1, &checkpoint);
+        } else {
+            set_decay_config_checkpoint(env, count, &checkpoint);
+            count += 1;
+        }
+        set_decay_config_checkpoint_count(env, count);
+    }
+
+    /// Binary search for the decay config in effect at `timestamp`.
+    fn config_at(env: &Env, timestamp: u64) -> DecayConfig {
+        let mut low = 0u32;
+        let mut high = get_decay_config_checkpoint_count(env);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let after =
+                get_decay_config_checkpoint(env, mid).is_some_and(|cp| cp.timestamp > timestamp);
+            if after {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+        low.checked_sub(1)
+            .and_then(|index| get_decay_config_checkpoint(env, index))
+            .map(|cp| cp.config)
+            .unwrap_or_else(|| get_decay_config(env))
+    }
+
+    /// Record the pre-checkpoint delegation state of an account as its first checkpoint,
+    /// so delegations made before checkpoints existed stay visible to older snapshots.
+    fn seed_delegation_history(env: &Env, account: &Address) {
+        if get_delegation_checkpoint_count(env, account) > 0 {
+            return;
+        }
+        let delegate = get_delegate(env, account);
+        let delegators = get_delegators(env, account);
+        if delegate.is_none() && delegators.is_empty() {
+            return;
+        }
+        let checkpoint = DelegationCheckpoint {
+            timestamp: 0,
+            delegate,
+            delegators,
+        };
+        set_delegation_checkpoint(env, account, 0, &checkpoint);
+        set_delegation_checkpoint_count(env, account, 1);
+    }
+
+    /// Record the current delegation state of an account for snapshot lookups.
+    fn write_delegation(env: &Env, account: &Address) {
+        let checkpoint = DelegationCheckpoint {
+            timestamp: env.ledger().timestamp(),
+            delegate: get_delegate(env, account),
+            delegators: get_delegators(env, account),
+        };
+
+        let count = get_delegation_checkpoint_count(env, account);
+        if count > 0 {
+            // Several changes in the same ledger collapse into one checkpoint
+            let last = get_delegation_checkpoint(env, account, count - 1);
+            if last.is_some_and(|cp| cp.timestamp == checkpoint.timestamp) {
+                set_delegation_checkpoint(env, account, count - 1, &checkpoint);
+                return;
+            }
+        }
+        set_delegation_checkpoint(env, account, count, &checkpoint);
+        set_delegation_checkpoint_count(env, account, count + 1);
+    }
+
+    /// Delegation state of an account at `timestamp`.
+    ///
+    /// Accounts without delegation checkpoints have never changed delegation and
+    /// fall back to the current state.
+    fn delegation_at(env: &Env, account: &Address, timestamp: u64) -> DelegationCheckpoint {
+        let count = get_delegation_checkpoint_count(env, account);
+        if count == 0 {
+            return DelegationCheckpoint {
+                timestamp,
+                delegate: get_delegate(env, account),
+                delegators: get_delegators(env, account),
+            };
+        }
+
+        let mut low = 0u32;
+        let mut high = count;
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let after = get_delegation_checkpoint(env, account, mid)
+                .is_some_and(|cp| cp.timestamp > timestamp);
+            if after {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+        low.checked_sub(1)
+            .and_then(|index| get_delegation_checkpoint(env, account, index))
+            .unwrap_or(DelegationCheckpoint {
+                timestamp: 0,
+                delegate: None,
+                delegators: Vec::new(env),
+            })
+    }
+
+    fn count_category(env: &Env, account: &Address, category_id: u32) -> u32 {
+        let Some(category) = categories::get(env, category_id) else {
+            return 0;
+        };
+        get_grants(env, account)
+            .iter()
+            .filter(|g| category.min_valor_id <= g.valor_id && g.valor_id <= category.max_valor_id)
+            .count() as u32
+    }
+
+    /// Remove a token from its owner, update their stats and keep a revocation record.
+    /// Returns the former owner, the token's valor ID and the owner's new level.
+    fn remove_token(
+        env: &Env,
+        token_id: u64,
+        reason: &String,
+        renounced: bool,
+    ) -> Result<(Address, u64, u64), ValocracyError> {
+        // Get token owner
+        let owner = get_token_owner(env, token_id).ok_or(ValocracyError::NonExistentToken)?;
+
+        let valor_id = get_token_valor_id(env, token_id).ok_or(ValocracyError::NonExistentToken)?;
+
+        // Use the rarity the badge was minted with, which is what it added to level and shares
+        let mut grants = get_grants(env, &owner);
+        let (rarity, permanent) = match grants.iter().position(|g| g.token_id == token_id) {
+            Some(index) => {
+                let grant = grants.get_unchecked(index as u32);
+                grants.remove(index as u32);
+                (grant.rarity, grant.permanent)
+            }
+            None => {
+                // Badges minted before grants were recorded only lower the level
+                let valor = get_valor(env, valor_id).ok_or(ValocracyError::NonExistentValor)?;
+                (valor.rarity, false)
+            }
+        };
+
+        let current_stats =
+            get_user_stats(env, &owner).ok_or(ValocracyError::NonExistentAccount)?;
+        let new_level = current_stats.level.saturating_sub(rarity);
+        // Only badges that raised the permanent level lower it again
+        let new_permanent = if permanent {
+            current_stats.permanent_level.saturating_sub(rarity)
+        } else {
+            current_stats.permanent_level
+        };
+
+        let mut new_stats = UserStats {
+            level: new_level,
+            permanent_level: new_permanent,
+            expiry: current_stats.expiry,
+            verified: current_stats.verified,
+        };
+        new_stats.expiry = Self::mana_grants(env, &owner, &new_stats, &grants)
+            .iter()
+            .map(|g| g.expiry)
+            .max()
+            .unwrap_or(0);
+        Self::write_stats(env, &owner, &new_stats, &grants);
+
+        // Replace token entries with an audit record
+        remove_token_owner(env, token_id);
+        remove_token_valor_id(env, token_id);
+        let revocation = Revocation {
+            owner: owner.clone(),
+            valor_id,
+            revoked_at: env.ledger().timestamp(),
+            reason: reason.clone(),
+            renounced,
+        };
+        set_revocation(env, token_id, &revocation);
+
+        extend_instance_ttl(env);
+
+        // Burn the Treasury shares granted for this badge, up to what the holder still has
+        if let Some(treasury) = get_treasury(env) {
+            let held: i128 = env.invoke_contract(
+                &treasury,
+                &Symbol::new(env, "shares_of"),
+                (owner.clone(),).into_val(env),
+            );
+            let shares = i128::from(rarity).min(held);
+            if shares > 0 {
+                let _res: () = env.invoke_contract(
+                    &treasury,
+                    &Symbol::new(env, "burn"),
+                    (owner.clone(), shares).into_val(env),
+                );
+            }
+        }
+
+        Ok((owner, valor_id, new_level))
+    }
+
+    fn remove_delegator(env: &Env, delegatee: &Address, delegator: &Address) {
+        let mut delegators = get_delegators(env, delegatee);
+        if let Some(index) = delegators.first_index_of(delegator) {
+            delegators.remove(index);
+            set_delegators(env, delegatee, &delegators);
+        }
+    }
+
+    /// Encode the message a backend signer signs to authorize `action`.
+    fn signed_payload(env: &Env, action: &SignedAction, nonce: u64, expiry: u64) -> Bytes {
+        SignedMessage {
+            version: SIGNATURE_VERSION,
+            contract: env.current_contract_address(),
+            network_id: env.ledger().network_id(),
+            action: action.clone(),
+            nonce,
+            expiry,
+        }
+        .to_xdr(env)
+    }
+
+    /// Verify a backend signature from `signer` over `action`. The signer must be currently
+    /// valid and allowed to sign for the minted valor (or unrestricted when none is minted).
     fn verify_signature(
         env: &Env,
-        payload: &Bytes,
+        action: &SignedAction,
+        signer: &BytesN<32>,
         signature: &BytesN<64>,
-        account: &Address,
         nonce: u64,
         expiry: u64,
     ) -> Result<(), ValocracyError> {
-        let signer = get_signer(env).ok_or(ValocracyError::NotInitialized)?;
+        let (account, valor_id) = match action {
+            SignedAction::SelfRegister(account) => (account, get_member_valor_id(env)),
+            SignedAction::GuardianMint(account, valor_id) => (account, Some(*valor_id)),
+            SignedAction::Renew(account) => (account, None),
+        };
+
+        let info = get_signers(env)
+            .iter()
+            .find(|s| &s.public_key == signer)
+            .ok_or(ValocracyError::SignerNotAuthorized)?;
+
+        if info.expires_at != 0 && env.ledger().timestamp() > info.expires_at {
+            return Err(ValocracyError::SignerNotAuthorized);
+        }
+
+        if !info.allowed_valor_ids.is_empty() {
+            let allowed = valor_id.is_some_and(|id| info.allowed_valor_ids.contains(id));
+            if !allowed {
+                return Err(ValocracyError::SignerNotAuthorized);
+            }
+        }
 
         // Check expiry
         if env.ledger().timestamp() > expiry {
@@ -675,42 +1678,80 @@ impl ValocracyContract {
         set_nonce_used(env, account, nonce);
 
         // Verify signature
-        env.crypto().ed25519_verify(&signer, payload, signature);
+        let payload = Self::signed_payload(env, action, nonce, expiry);
+        env.crypto().ed25519_verify(signer, &payload, signature);
 
         Ok(())
     }
 
     fn mint_internal(env: &Env, account: &Address, valor_id: u64) -> Result<u64, ValocracyError> {
-        // Validate badge ID range
-        Self::validate_badge_id(valor_id)?;
+        let (token_id, rarity) = Self::mint_badge(env, account, valor_id)?;
+        Self::allocate_shares(env, account, i128::from(rarity));
+        Ok(token_id)
+    }
+
+    /// Mint a badge without allocating Treasury shares. Returns the token ID and its rarity.
+    fn mint_badge(
+        env: &Env,
+        account: &Address,
+        valor_id: u64,
+    ) -> Result<(u64, u64), ValocracyError> {
+        // Validate badge ID against the category registry
+        let category =
+            categories::category_of(env, valor_id).ok_or(ValocracyError::InvalidValorId)?;
+        if category.mint_policy == MintPolicy::Never {
+            return Err(ValocracyError::BadgeNotMintable);
+        }
 
         // Get valor rarity
         let valor = get_valor(env, valor_id).ok_or(ValocracyError::NonExistentValor)?;
         let rarity = valor.rarity;
+        if rarity > category.rarity_cap {
+            return Err(ValocracyError::RarityCapExceeded);
+        }
 
         let current_stats = get_user_stats(env, account);
         let current_level = current_stats.as_ref().map_or(0, |s| s.level);
         let current_permanent = current_stats.as_ref().map_or(0, |s| s.permanent_level);
 
-        // Calculate new stats
+        // Create new token
+        let total_supply = get_total_supply(env);
+        let token_id = total_supply + 1;
+        set_total_supply(env, token_id);
+
+        // Calculate new stats. Only the new badge starts a fresh decay window.
         let current_time = env.ledger().timestamp();
         let new_level = current_level + rarity;
-        let new_expiry = current_time + VACANCY_PERIOD;
+        let grant_expiry = current_time + get_decay_config(env).vacancy_period;
+        let current_expiry = current_stats.as_ref().map_or(0, |s| s.expiry);
         let current_verified = current_stats.as_ref().is_some_and(|s| s.verified);
 
+        // Badges of permanent valors also raise the permanent level
+        let permanent = is_permanent_valor(env, valor_id);
+        let new_permanent = if permanent {
+            current_permanent + rarity
+        } else {
+            current_permanent
+        };
+
         let new_stats = UserStats {
             level: new_level,
-            permanent_level: current_permanent, // Preserve existing permanent level
-            expiry: new_expiry,
+            permanent_level: new_permanent,
+            expiry: current_expiry.max(grant_expiry),
             verified: current_verified,
         };
 
-        set_user_stats(env, account, &new_stats);
+        let mut grants = get_grants(env, account);
+        grants.push_back(Grant {
+            token_id,
+            valor_id,
+            rarity,
+            minted_at: current_time,
+            expiry: grant_expiry,
+            permanent,
+        });
 
-        // Create new token
-        let total_supply = get_total_supply(env);
-        let token_id = total_supply + 1;
-        set_total_supply(env, token_id);
+        Self::write_stats(env, account, &new_stats, &grants);
 
         // Link token to valor and owner
         set_token_valor_id(env, token_id, valor_id);
@@ -726,7 +1767,11 @@ impl ValocracyContract {
         }
         .publish(env);
 
-        // Grant Treasury shares equal to badge rarity.
+        Ok((token_id, rarity))
+    }
+
+    /// Grant Treasury shares equal to badge rarity.
+    fn allocate_shares(env: &Env, account: &Address, shares: i128) {
         if let Some(treasury) = get_treasury(env) {
             // We ignore errors here to not block minting if treasury fails?
             // Or should we fail?
@@ -734,8 +1779,6 @@ impl ValocracyContract {
             // If it fails, the user gets no shares. That seems bad.
             // Let's propagate error.
 
-            // i128::from(rarity) might panic if rarity is too big, but u64 fits in i128 easily.
-            let shares = i128::from(rarity);
             if shares > 0 {
                 let _res: () = env.invoke_contract(
                     &treasury,
@@ -744,8 +1787,6 @@ impl ValocracyContract {
                 );
             }
         }
-
-        Ok(token_id)
     }
 }
 
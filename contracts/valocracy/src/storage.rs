@@ -1,8 +1,11 @@
 //! Storage helpers for the Valocracy contract
 
-use soroban_sdk::{contracttype, Address, BytesN, Env};
+use soroban_sdk::{contracttype, Address, BytesN, Env, Vec};
 
-use crate::types::{UserStats, Valor};
+use crate::types::{
+    Category, Checkpoint, DecayConfig, DecayConfigCheckpoint, DelegationCheckpoint, ExpiryBucket,
+    Grant, Revocation, Signer, SupplyCheckpoint, UserStats, Valor,
+};
 
 /// Storage keys for the contract
 #[contracttype]
@@ -26,8 +29,58 @@ pub enum DataKey {
     UserStats(Address),
     /// Token ID to owner address mapping
     TokenOwner(u64),
+    /// Single signer key written by earlier versions
     Signer,
     UsedNonce(Address, u64),
+    /// Delegator -> delegatee receiving its voting weight
+    Delegate(Address),
+    /// Delegatee -> accounts currently delegating to it
+    Delegators(Address),
+    /// Account -> number of stats checkpoints
+    CheckpointCount(Address),
+    /// (Account, index) -> stats checkpoint
+    Checkpoint(Address, u32),
+    /// Number of delegation checkpoints recorded for an account
+    DelegationCheckpointCount(Address),
+    /// Delegation checkpoint of an account by index
+    DelegationCheckpoint(Address, u32),
+    /// Account -> expiry of the badges it held before per-badge grants were recorded
+    LegacyExpiry(Address),
+    /// Number of accounts with level > 0
+    TotalMembers,
+    /// Sum of all account levels
+    TotalLevel,
+    /// Sum of permanent levels above the floor, counted on top of decaying Mana
+    TotalPermanentExtra,
+    /// Account -> its permanent level above the floor included in the total
+    PermanentExtra(Address),
+    /// Expiry bucket index -> decaying Mana expiring in that bucket
+    ExpiryBucket(u64),
+    /// Number of total supply checkpoints
+    SupplyCheckpointCount,
+    /// Index -> total supply checkpoint
+    SupplyCheckpoint(u32),
+    /// Governance-set Mana decay parameters
+    DecayConfig,
+    /// Number of decay config checkpoints
+    DecayConfigCheckpointCount,
+    /// Decay config checkpoint by index
+    DecayConfigCheckpoint(u32),
+    /// Latest expiry recorded in the expiry buckets
+    MaxExpiry,
+    /// Account -> badges held, oldest first, each with its own expiry.
+    /// Doubles as the owner -> token IDs index.
+    Grants(Address),
+    /// Category ID -> badge category definition
+    Category(u32),
+    /// IDs of all defined badge categories
+    CategoryIds,
+    /// Token ID -> record of its revocation
+    Revocation(u64),
+    /// Backend signers accepted by verify_signature
+    Signers,
+    /// Valor ID -> whether its badges add to the permanent level
+    PermanentValor(u64),
 }
 
 // TTL constants (in ledgers, ~5 seconds each)
@@ -178,12 +231,55 @@ pub fn remove_token_valor_id(env: &Env, token_id: u64) {
     env.storage().persistent().remove(&key);
 }
 
-pub fn get_signer(env: &Env) -> Option<BytesN<32>> {
-    env.storage().instance().get(&DataKey::Signer)
+pub fn get_revocation(env: &Env, token_id: u64) -> Option<Revocation> {
+    let key = DataKey::Revocation(token_id);
+    let result = env.storage().persistent().get(&key);
+    if result.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    result
+}
+
+pub fn set_revocation(env: &Env, token_id: u64, revocation: &Revocation) {
+    let key = DataKey::Revocation(token_id);
+    env.storage().persistent().set(&key, revocation);
+    extend_persistent_ttl(env, &key);
 }
 
-pub fn set_signer(env: &Env, signer: &BytesN<32>) {
-    env.storage().instance().set(&DataKey::Signer, signer);
+pub fn is_permanent_valor(env: &Env, valor_id: u64) -> bool {
+    let key = DataKey::PermanentValor(valor_id);
+    let result = env.storage().persistent().get(&key);
+    if result.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    result.unwrap_or(false)
+}
+
+pub fn set_permanent_valor(env: &Env, valor_id: u64, permanent: bool) {
+    let key = DataKey::PermanentValor(valor_id);
+    env.storage().persistent().set(&key, &permanent);
+    extend_persistent_ttl(env, &key);
+}
+
+pub fn get_signers(env: &Env) -> Vec<Signer> {
+    if let Some(signers) = env.storage().instance().get(&DataKey::Signers) {
+        return signers;
+    }
+    // Fall back to the single key stored before multiple signers existed
+    let legacy: Option<BytesN<32>> = env.storage().instance().get(&DataKey::Signer);
+    let mut signers = Vec::new(env);
+    if let Some(public_key) = legacy {
+        signers.push_back(Signer {
+            public_key,
+            allowed_valor_ids: Vec::new(env),
+            expires_at: 0,
+        });
+    }
+    signers
+}
+
+pub fn set_signers(env: &Env, signers: &Vec<Signer>) {
+    env.storage().instance().set(&DataKey::Signers, signers);
 }
 
 pub fn is_nonce_used(env: &Env, account: &Address, nonce: u64) -> bool {
@@ -198,3 +294,318 @@ pub fn set_nonce_used(env: &Env, account: &Address, nonce: u64) {
     // Let's stick to persistent default
     extend_persistent_ttl(env, &key);
 }
+
+pub fn get_delegate(env: &Env, delegator: &Address) -> Option<Address> {
+    let key = DataKey::Delegate(delegator.clone());
+    let result = env.storage().persistent().get(&key);
+    if result.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    result
+}
+
+pub fn set_delegate(env: &Env, delegator: &Address, delegatee: &Address) {
+    let key = DataKey::Delegate(delegator.clone());
+    env.storage().persistent().set(&key, delegatee);
+    extend_persistent_ttl(env, &key);
+}
+
+pub fn remove_delegate(env: &Env, delegator: &Address) {
+    let key = DataKey::Delegate(delegator.clone());
+    env.storage().persistent().remove(&key);
+}
+
+pub fn get_delegators(env: &Env, delegatee: &Address) -> Vec<Address> {
+    let key = DataKey::Delegators(delegatee.clone());
+    let result = env.storage().persistent().get(&key);
+    if result.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    result.unwrap_or(Vec::new(env))
+}
+
+pub fn set_delegators(env: &Env, delegatee: &Address, delegators: &Vec<Address>) {
+    let key = DataKey::Delegators(delegatee.clone());
+    if delegators.is_empty() {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, delegators);
+    extend_persistent_ttl(env, &key);
+}
+
+pub fn get_checkpoint_count(env: &Env, account: &Address) -> u32 {
+    let key = DataKey::CheckpointCount(account.clone());
+    let result = env.storage().persistent().get(&key);
+    if result.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    result.unwrap_or(0)
+}
+
+pub fn set_checkpoint_count(env: &Env, account: &Address, count: u32) {
+    let key = DataKey::CheckpointCount(account.clone());
+    env.storage().persistent().set(&key, &count);
+    extend_persistent_ttl(env, &key);
+}
+
+pub fn get_checkpoint(env: &Env, account: &Address, index: u32) -> Option<Checkpoint> {
+    let key = DataKey::Checkpoint(account.clone(), index);
+    let result = env.storage().persistent().get(&key);
+    if result.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    result
+}
+
+pub fn set_checkpoint(env: &Env, account: &Address, index: u32, checkpoint: &Checkpoint) {
+    let key = DataKey::Checkpoint(account.clone(), index);
+    env.storage().persistent().set(&key, checkpoint);
+    extend_persistent_ttl(env, &key);
+}
+
+pub fn get_delegation_checkpoint_count(env: &Env, account: &Address) -> u32 {
+    let key = DataKey::DelegationCheckpointCount(account.clone());
+    let result = env.storage().persistent().get(&key);
+    if result.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    result.unwrap_or(0)
+}
+
+pub fn set_delegation_checkpoint_count(env: &Env, account: &Address, count: u32) {
+    let key = DataKey::DelegationCheckpointCount(account.clone());
+    env.storage().persistent().set(&key, &count);
+    extend_persistent_ttl(env, &key);
+}
+
+pub fn get_delegation_checkpoint(
+    env: &Env,
+    account: &Address,
+    index: u32,
+) -> Option<DelegationCheckpoint> {
+    let key = DataKey::DelegationCheckpoint(account.clone(), index);
+    let result = env.storage().persistent().get(&key);
+    if result.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    result
+}
+
+pub fn set_delegation_checkpoint(
+    env: &Env,
+    account: &Address,
+    index: u32,
+    checkpoint: &DelegationCheckpoint,
+) {
+    let key = DataKey::DelegationCheckpoint(account.clone(), index);
+    env.storage().persistent().set(&key, checkpoint);
+    extend_persistent_ttl(env, &key);
+}
+
+pub fn get_total_members(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TotalMembers)
+        .unwrap_or(0)
+}
+
+pub fn set_total_members(env: &Env, members: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::TotalMembers, &members);
+}
+
+pub fn get_total_level(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TotalLevel)
+        .unwrap_or(0)
+}
+
+pub fn set_total_level(env: &Env, level: u64) {
+    env.storage().instance().set(&DataKey::TotalLevel, &level);
+}
+
+pub fn get_total_permanent_extra(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TotalPermanentExtra)
+        .unwrap_or(0)
+}
+
+pub fn set_total_permanent_extra(env: &Env, extra: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::TotalPermanentExtra, &extra);
+}
+
+pub fn get_permanent_extra(env: &Env, account: &Address) -> u64 {
+    let key = DataKey::PermanentExtra(account.clone());
+    let result = env.storage().persistent().get(&key);
+    if result.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    result.unwrap_or(0)
+}
+
+pub fn set_permanent_extra(env: &Env, account: &Address, extra: u64) {
+    let key = DataKey::PermanentExtra(account.clone());
+    if extra == 0 {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, &extra);
+    extend_persistent_ttl(env, &key);
+}
+
+pub fn get_expiry_bucket(env: &Env, bucket: u64) -> ExpiryBucket {
+    let key = DataKey::ExpiryBucket(bucket);
+    let result = env.storage().persistent().get(&key);
+    if result.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    result.unwrap_or_default()
+}
+
+pub fn set_expiry_bucket(env: &Env, bucket: u64, data: &ExpiryBucket) {
+    let key = DataKey::ExpiryBucket(bucket);
+    env.storage().persistent().set(&key, data);
+    extend_persistent_ttl(env, &key);
+}
+
+pub fn get_supply_checkpoint_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::SupplyCheckpointCount)
+        .unwrap_or(0)
+}
+
+pub fn set_supply_checkpoint_count(env: &Env, count: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::SupplyCheckpointCount, &count);
+}
+
+pub fn get_supply_checkpoint(env: &Env, index: u32) -> Option<SupplyCheckpoint> {
+    let key = DataKey::SupplyCheckpoint(index);
+    let result = env.storage().persistent().get(&key);
+    if result.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    result
+}
+
+pub fn set_supply_checkpoint(env: &Env, index: u32, checkpoint: &SupplyCheckpoint) {
+    let key = DataKey::SupplyCheckpoint(index);
+    env.storage().persistent().set(&key, checkpoint);
+    extend_persistent_ttl(env, &key);
+}
+
+pub fn get_decay_config(env: &Env) -> DecayConfig {
+    env.storage()
+        .instance()
+        .get(&DataKey::DecayConfig)
+        .unwrap_or_else(|| DecayConfig::default(env))
+}
+
+pub fn set_decay_config(env: &Env, config: &DecayConfig) {
+    env.storage().instance().set(&DataKey::DecayConfig, config);
+}
+
+pub fn get_max_expiry(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MaxExpiry)
+        .unwrap_or(0)
+}
+
+pub fn set_max_expiry(env: &Env, expiry: u64) {
+    env.storage().instance().set(&DataKey::MaxExpiry, &expiry);
+}
+
+pub fn get_grants(env: &Env, account: &Address) -> Vec<Grant> {
+    let key = DataKey::Grants(account.clone());
+    let result = env.storage().persistent().get(&key);
+    if result.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    result.unwrap_or(Vec::new(env))
+}
+
+pub fn set_grants(env: &Env, account: &Address, grants: &Vec<Grant>) {
+    let key = DataKey::Grants(account.clone());
+    if grants.is_empty() {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, grants);
+    extend_persistent_ttl(env, &key);
+}
+
+pub fn get_legacy_expiry(env: &Env, account: &Address) -> Option<u64> {
+    let key = DataKey::LegacyExpiry(account.clone());
+    let result = env.storage().persistent().get(&key);
+    if result.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    result
+}
+
+pub fn set_legacy_expiry(env: &Env, account: &Address, expiry: u64) {
+    let key = DataKey::LegacyExpiry(account.clone());
+    env.storage().persistent().set(&key, &expiry);
+    extend_persistent_ttl(env, &key);
+}
+
+pub fn get_category(env: &Env, category_id: u32) -> Option<Category> {
+    let key = DataKey::Category(category_id);
+    let result = env.storage().persistent().get(&key);
+    if result.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    result
+}
+
+pub fn set_category(env: &Env, category: &Category) {
+    let key = DataKey::Category(category.id);
+    env.storage().persistent().set(&key, category);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Registered category IDs, or `None` if the registry was never seeded.
+pub fn get_category_ids(env: &Env) -> Option<Vec<u32>> {
+    env.storage().instance().get(&DataKey::CategoryIds)
+}
+
+pub fn set_category_ids(env: &Env, ids: &Vec<u32>) {
+    env.storage().instance().set(&DataKey::CategoryIds, ids);
+}
+
+pub fn get_decay_config_checkpoint_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::DecayConfigCheckpointCount)
+        .unwrap_or(0)
+}
+
+pub fn set_decay_config_checkpoint_count(env: &Env, count: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::DecayConfigCheckpointCount, &count);
+}
+
+pub fn get_decay_config_checkpoint(env: &Env, index: u32) -> Option<DecayConfigCheckpoint> {
+    let key = DataKey::DecayConfigCheckpoint(index);
+    let result = env.storage().persistent().get(&key);
+    if result.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    result
+}
+
+pub fn set_decay_config_checkpoint(env: &Env, index: u32, checkpoint: &DecayConfigCheckpoint) {
+    let key = DataKey::DecayConfigCheckpoint(index);
+    env.storage().persistent().set(&key, checkpoint);
+    extend_persistent_ttl(env, &key);
+}
@@ -0,0 +1,230 @@
+//! Aggregate Mana supply tracking.
+//!
+//! Every account contributes the decay floor plus the bonus of each of its
+//! grants, which decays to zero at the grant's expiry. Grant bonuses are grouped
+//! into expiry buckets and each bucket is evaluated at its mean expiry, so the
+//! current total can be computed without iterating accounts.
+//! Past totals are interpolated between supply checkpoints written on each change.
+//! An account's Mana is the larger of its decaying Mana and its permanent level,
+//! so the part of the permanent level above the floor is added on top. This
+//! bounds every account's Mana from above and is exact once its bonuses expire.
+
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::decay;
+use crate::storage::{
+    get_decay_config, get_expiry_bucket, get_max_expiry, get_permanent_extra,
+    get_supply_checkpoint, get_supply_checkpoint_count, get_total_level, get_total_members,
+    get_total_permanent_extra, set_expiry_bucket, set_max_expiry, set_permanent_extra,
+    set_supply_checkpoint, set_supply_checkpoint_count, set_total_level, set_total_members,
+    set_total_permanent_extra,
+};
+use crate::types::{DecayConfig, Grant, SupplyCheckpoint, UserStats};
+
+/// Width of an expiry bucket: 14 days in seconds.
+pub const BUCKET_WIDTH: u64 = 14 * 24 * 60 * 60;
+
+fn bucket_of(timestamp: u64) -> u64 {
+    timestamp / BUCKET_WIDTH
+}
+
+fn update_bucket(env: &Env, extra: u64, expiry: u64, add: bool) {
+    if extra == 0 {
+        return;
+    }
+    let index = bucket_of(expiry);
+    let mut bucket = get_expiry_bucket(env, index);
+    let extra = u128::from(extra);
+    let weighted = extra * u128::from(expiry);
+    if add {
+        bucket.extra += extra;
+        bucket.weighted += weighted;
+        if expiry > get_max_expiry(env) {
+            set_max_expiry(env, expiry);
+        }
+    } else {
+        bucket.extra = bucket.extra.saturating_sub(extra);
+        bucket.weighted = bucket.weighted.saturating_sub(weighted);
+    }
+    set_expiry_bucket(env, index, &bucket);
+}
+
+fn update_buckets(env: &Env, config: &DecayConfig, grants: &Vec<Grant>, add: bool) {
+    for (extra, expiry) in decay::bonuses(config, grants) {
+        update_bucket(env, extra, expiry, add);
+    }
+}
+
+/// Evaluate the total from the current aggregates.
+/// Valid for any timestamp at or after the latest supply checkpoint.
+fn live_total(env: &Env, config: &DecayConfig, timestamp: u64) -> u64 {
+    let mut bonus: u64 = 0;
+    for index in bucket_of(timestamp)..=bucket_of(get_max_expiry(env)) {
+        let bucket = get_expiry_bucket(env, index);
+        if bucket.extra == 0 {
+            continue;
+        }
+        let mean_expiry = bucket.weighted / bucket.extra;
+        bonus += decay::decayed(config, bucket.extra as u64, mean_expiry as u64, timestamp);
+    }
+    get_total_members(env) * config.floor + bonus + get_total_permanent_extra(env)
+}
+
+/// Append a supply checkpoint around a change happening now.
+fn push_checkpoint(env: &Env, before: u64, after: u64) {
+    let now = env.ledger().timestamp();
+    let count = get_supply_checkpoint_count(env);
+    if count > 0 {
+        if let Some(mut last) = get_supply_checkpoint(env, count - 1) {
+            // Several changes in the same ledger collapse into one checkpoint
+            if last.timestamp == now {
+                last.mana_after = after;
+                set_supply_checkpoint(env, count - 1, &last);
+                return;
+            }
+        }
+    }
+    let checkpoint = SupplyCheckpoint {
+        timestamp: now,
+        mana_before: before,
+        mana_after: after,
+    };
+    set_supply_checkpoint(env, count, &checkpoint);
+    set_supply_checkpoint_count(env, count + 1);
+}
+
+/// Apply an account's stats change to the aggregates and record a supply checkpoint.
+///
+/// Bonuses and permanent extras are split off the floor with the current config,
+/// so after a floor change the aggregates are approximate until the affected
+/// accounts change again.
+pub fn record_change(
+    env: &Env,
+    account: &Address,
+    old: Option<&UserStats>,
+    old_grants: &Vec<Grant>,
+    new: &UserStats,
+    new_grants: &Vec<Grant>,
+) {
+    let now = env.ledger().timestamp();
+    let config = get_decay_config(env);
+    let before = live_total(env, &config, now);
+
+    let mut members = get_total_members(env);
+    let mut total_level = get_total_level(env);
+    let mut total_extra = get_total_permanent_extra(env);
+
+    let mut after = before;
+    if let Some(old) = old.filter(|s| s.level > 0) {
+        let old_extra = get_permanent_extra(env, account);
+        members = members.saturating_sub(1);
+        total_level = total_level.saturating_sub(old.level);
+        total_extra = total_extra.saturating_sub(old_extra);
+        update_buckets(env, &config, old_grants, false);
+        after = after.saturating_sub(decay::mana(&config, 0, old_grants, now) + old_extra);
+    }
+    let mut new_extra = 0;
+    if new.level > 0 {
+        new_extra = new.permanent_level.saturating_sub(config.floor);
+        members += 1;
+        total_level += new.level;
+        total_extra += new_extra;
+        update_buckets(env, &config, new_grants, true);
+        after += decay::mana(&config, 0, new_grants, now) + new_extra;
+    }
+
+    set_total_members(env, members);
+    set_total_level(env, total_level);
+    set_total_permanent_extra(env, total_extra);
+    set_permanent_extra(env, account, new_extra);
+
+    push_checkpoint(env, before, after);
+}
+
+/// Add an account registered before supply tracking to the aggregates.
+///
+/// Its Mana was already part of the supply, so the latest checkpoint is raised
+/// instead of recording a jump. Checkpoints written before the account is
+/// enrolled still leave it out.
+pub fn record_enrollment(env: &Env, account: &Address, stats: &UserStats, grants: &Vec<Grant>) {
+    if stats.level == 0 {
+        return;
+    }
+    let now = env.ledger().timestamp();
+    let config = get_decay_config(env);
+    let before = live_total(env, &config, now);
+
+    let extra = stats.permanent_level.saturating_sub(config.floor);
+    set_total_members(env, get_total_members(env) + 1);
+    set_total_level(env, get_total_level(env) + stats.level);
+    set_total_permanent_extra(env, get_total_permanent_extra(env) + extra);
+    set_permanent_extra(env, account, extra);
+    update_buckets(env, &config, grants, true);
+    let added = decay::mana(&config, 0, grants, now) + extra;
+
+    let count = get_supply_checkpoint_count(env);
+    let last = count
+        .checked_sub(1)
+        .and_then(|index| get_supply_checkpoint(env, index));
+    match last.filter(|cp| cp.timestamp == now) {
+        Some(mut last) => {
+            last.mana_before += added;
+            last.mana_after += added;
+            set_supply_checkpoint(env, count - 1, &last);
+        }
+        None => push_checkpoint(env, before + added, before + added),
+    }
+}
+
+/// Record the jump in total supply caused by a decay config change.
+/// Must be called after the new config has been stored.
+pub fn record_config_change(env: &Env, old: &DecayConfig) {
+    let now = env.ledger().timestamp();
+    let before = live_total(env, old, now);
+    let after = live_total(env, &get_decay_config(env), now);
+    push_checkpoint(env, before, after);
+}
+
+/// Total Mana supply at `timestamp`.
+///
+/// Between two past checkpoints the total is interpolated linearly, which can
+/// slightly overestimate it when accounts expire in between.
+pub fn total_mana_at(env: &Env, timestamp: u64) -> u64 {
+    let count = get_supply_checkpoint_count(env);
+
+    // Latest checkpoint at or before `timestamp`
+    let mut low = 0u32;
+    let mut high = count;
+    while low < high {
+        let mid = low + (high - low) / 2;
+        match get_supply_checkpoint(env, mid) {
+            Some(cp) if cp.timestamp <= timestamp => low = mid + 1,
+            _ => high = mid,
+        }
+    }
+    if low == count {
+        return live_total(env, &get_decay_config(env), timestamp);
+    }
+    if low == 0 {
+        // Before the first checkpoint the supply is what it was when it was written
+        return get_supply_checkpoint(env, 0).map_or(0, |cp| cp.mana_before);
+    }
+
+    let (Some(start), Some(end)) = (
+        get_supply_checkpoint(env, low - 1),
+        get_supply_checkpoint(env, low),
+    ) else {
+        return 0;
+    };
+
+    let span = u128::from(end.timestamp - start.timestamp);
+    let elapsed = u128::from(timestamp - start.timestamp);
+    let from = u128::from(start.mana_after);
+    let to = u128::from(end.mana_before);
+    let value = if from >= to {
+        from - (from - to) * elapsed / span
+    } else {
+        from + (to - from) * elapsed / span
+    };
+    value as u64
+}
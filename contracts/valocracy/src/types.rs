@@ -1,6 +1,8 @@
 //! Data types for the Valocracy contract
 
-use soroban_sdk::{contracttype, String};
+use soroban_sdk::{contracttype, Address, BytesN, Env, String, Vec};
+
+use crate::{MEMBER_FLOOR, VACANCY_PERIOD};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -17,3 +19,184 @@ pub struct Valor {
     pub rarity: u64,
     pub metadata: String,
 }
+
+/// Snapshot of the Mana-relevant stats of an account, written on every change.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Checkpoint {
+    pub timestamp: u64,
+    pub level: u64,
+    pub permanent_level: u64,
+    pub expiry: u64,
+    pub grants: Vec<Grant>,
+}
+
+/// Snapshot of an account's delegation state, written on every delegation change.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DelegationCheckpoint {
+    pub timestamp: u64,
+    pub delegate: Option<Address>,
+    pub delegators: Vec<Address>,
+}
+
+/// A held badge with its own decay window.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Grant {
+    pub token_id: u64,
+    pub valor_id: u64,
+    pub rarity: u64,
+    pub minted_at: u64,
+    pub expiry: u64,
+    /// Whether the badge's rarity was added to the permanent level
+    pub permanent: bool,
+}
+
+/// Decaying Mana scheduled to expire within one expiry bucket.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ExpiryBucket {
+    /// Sum of the decaying bonus of grants expiring in this bucket
+    pub extra: u128,
+    /// Sum of bonus * expiry, used to derive the bucket's mean expiry
+    pub weighted: u128,
+}
+
+/// Total Mana supply around an aggregate change, for historical lookups.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SupplyCheckpoint {
+    pub timestamp: u64,
+    pub mana_before: u64,
+    pub mana_after: u64,
+}
+
+/// Shape of the Mana decay between a badge mint and its expiry.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum DecayCurve {
+    /// Bonus decreases linearly to zero at expiry
+    Linear = 0,
+    /// Full bonus until expiry, then drops to zero
+    Step = 1,
+    /// Bonus halves every `half_life` seconds and drops to zero at expiry
+    Exponential = 2,
+    /// Full bonus for `plateau` seconds, then linear to zero at expiry
+    PlateauLinear = 3,
+}
+
+/// Decay parameters in effect from `timestamp`, written on every config change.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DecayConfigCheckpoint {
+    pub timestamp: u64,
+    pub config: DecayConfig,
+}
+
+/// Governance-settable Mana decay parameters.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DecayConfig {
+    pub curve: DecayCurve,
+    /// Seconds from a mint until its bonus has fully decayed
+    pub vacancy_period: u64,
+    /// Mana every registered account keeps after decay
+    pub floor: u64,
+    /// Exponential curve only: seconds for the bonus to halve
+    pub half_life: u64,
+    /// Plateau curve only: seconds of full bonus after a mint
+    pub plateau: u64,
+}
+
+impl DecayConfig {
+    pub fn default(_env: &Env) -> Self {
+        Self {
+            curve: DecayCurve::Linear,
+            vacancy_period: VACANCY_PERIOD, // 180 days
+            floor: MEMBER_FLOOR,
+            half_life: 0,
+            plateau: 0,
+        }
+    }
+}
+
+/// Who may mint badges of a category through `mint`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MintPolicy {
+    /// Only through `self_register`
+    SelfRegister,
+    /// Only the governor
+    Governor,
+    /// The governor or holders of a badge in the given category
+    HoldersOf(u32),
+    /// Any badge holder
+    AnyMember,
+    /// Not mintable after initialization
+    Never,
+}
+
+/// A governance-defined range of valor IDs sharing a minting policy.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Category {
+    pub id: u32,
+    pub name: String,
+    /// First valor ID of the category (inclusive)
+    pub min_valor_id: u64,
+    /// Last valor ID of the category (inclusive)
+    pub max_valor_id: u64,
+    pub mint_policy: MintPolicy,
+    /// Highest rarity a valor of this category may have
+    pub rarity_cap: u64,
+}
+
+/// Audit record of a token removed by governance or renounced by its holder.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Revocation {
+    pub owner: Address,
+    pub valor_id: u64,
+    pub revoked_at: u64,
+    pub reason: String,
+    /// True when the holder gave up the badge themselves
+    pub renounced: bool,
+}
+
+/// A backend key allowed to sign registrations, mints and renewals.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Signer {
+    pub public_key: BytesN<32>,
+    /// Valor IDs this key may sign for; empty allows all
+    pub allowed_valor_ids: Vec<u64>,
+    /// Timestamp after which the key is no longer accepted; 0 never expires
+    pub expires_at: u64,
+}
+
+/// Operation authorized by a backend signature, with the fields it covers.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SignedAction {
+    /// self_register(account)
+    SelfRegister(Address),
+    /// guardian_mint(account, valor_id)
+    GuardianMint(Address, u64),
+    /// renew(account)
+    Renew(Address),
+}
+
+/// Message signed by the backend, encoded as XDR.
+/// Binds a signature to one deployment on one network and to a single operation.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignedMessage {
+    pub version: u32,
+    pub contract: Address,
+    pub network_id: BytesN<32>,
+    pub action: SignedAction,
+    pub nonce: u64,
+    pub expiry: u64,
+}
@@ -1,4 +1,8 @@
-import { Client as GeneratedGovernorClient } from '../generated/governor/src/index.js';
+import { Client as GeneratedGovernorClient, VoteType } from '../generated/governor/src/index.js';
+import type { AssembledTransaction, Result } from '@stellar/stellar-sdk/contract';
+import type { u64 } from '@stellar/stellar-sdk/contract';
+
+export { VoteType };
 
 export class GovernorClient {
   private client: GeneratedGovernorClient;
@@ -20,4 +24,54 @@ export class GovernorClient {
     const tx = await this.client.get_proposal({ proposal_id: BigInt(proposalId) });
     return tx.result;
   }
+
+  // Vote For, Against or Abstain; returns the voting power used
+
+  async castVote(
+    voter: string,
+    proposalId: bigint,
+    vote: VoteType
+  ): Promise<AssembledTransaction<Result<u64>>> {
+    return await this.client.cast_vote({
+      voter,
+      proposal_id: BigInt(proposalId),
+      vote,
+    });
+  }
+
+  // Vote with a reason recorded in the vote event
+
+  async castVoteWithReason(
+    voter: string,
+    proposalId: bigint,
+    vote: VoteType,
+    reason: string
+  ): Promise<AssembledTransaction<Result<u64>>> {
+    return await this.client.cast_vote_with_reason({
+      voter,
+      proposal_id: BigInt(proposalId),
+      vote,
+      reason,
+    });
+  }
+
+  // Submit a vote signed off-chain by the voter's registered voting key
+
+  async castVoteBySig(
+    voter: string,
+    proposalId: bigint,
+    vote: VoteType,
+    nonce: bigint,
+    expiry: bigint,
+    signature: Buffer
+  ): Promise<AssembledTransaction<Result<u64>>> {
+    return await this.client.cast_vote_by_sig({
+      voter,
+      proposal_id: BigInt(proposalId),
+      vote,
+      nonce: BigInt(nonce),
+      expiry: BigInt(expiry),
+      signature,
+    });
+  }
 }
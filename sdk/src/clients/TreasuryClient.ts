@@ -18,6 +18,6 @@ export class TreasuryClient {
 
   async getClaimableBalance(member: string): Promise<bigint> {
     const tx = await this.client.get_claimable_balance({ member });
-    return tx.result;
+    return tx.result.unwrap();
   }
 }
@@ -1,4 +1,5 @@
 import { Client as GeneratedValocracyClient } from '../generated/valocracy/src/index.js';
+import type { Signer } from '../generated/valocracy/src/index.js';
 import type { AssembledTransaction, Result } from '@stellar/stellar-sdk/contract';
 import type { u64 } from '@stellar/stellar-sdk/contract';
 
@@ -34,15 +35,79 @@ export class ValocracyClient {
 
   async selfRegister(
     caller: string,
+    signer: Buffer,
     signature: Buffer,
     nonce: bigint,
     expiry: bigint
   ): Promise<AssembledTransaction<Result<u64>>> {
     return await this.client.self_register({
       caller,
+      signer,
       signature,
       nonce: BigInt(nonce),
       expiry: BigInt(expiry),
     });
   }
+
+  // Mint a badge with backend signature
+
+  async guardianMint(
+    account: string,
+    valorId: bigint,
+    signer: Buffer,
+    signature: Buffer,
+    nonce: bigint,
+    expiry: bigint
+  ): Promise<AssembledTransaction<Result<u64>>> {
+    return await this.client.guardian_mint({
+      account,
+      valor_id: BigInt(valorId),
+      signer,
+      signature,
+      nonce: BigInt(nonce),
+      expiry: BigInt(expiry),
+    });
+  }
+
+  // Refresh the decay window of every badge with backend signature
+
+  async renew(
+    account: string,
+    signer: Buffer,
+    signature: Buffer,
+    nonce: bigint,
+    expiry: bigint
+  ): Promise<AssembledTransaction<Result<u64>>> {
+    return await this.client.renew({
+      account,
+      signer,
+      signature,
+      nonce: BigInt(nonce),
+      expiry: BigInt(expiry),
+    });
+  }
+
+  // Revoke a badge (Governor only)
+
+  async revoke(tokenId: bigint, reason: string): Promise<AssembledTransaction<Result<void>>> {
+    return await this.client.revoke({ token_id: BigInt(tokenId), reason });
+  }
+
+  // Give up a badge held by the caller
+
+  async renounce(owner: string, tokenId: bigint): Promise<AssembledTransaction<Result<void>>> {
+    return await this.client.renounce({ owner, token_id: BigInt(tokenId) });
+  }
+
+  // Add or replace a backend signer (Governor only)
+
+  async setSigner(signer: Signer): Promise<AssembledTransaction<Result<void>>> {
+    return await this.client.set_signer({ signer });
+  }
+
+  // Remove a backend signer (Governor only)
+
+  async removeSigner(publicKey: Buffer): Promise<AssembledTransaction<Result<void>>> {
+    return await this.client.remove_signer({ public_key: publicKey });
+  }
 }
@@ -46,113 +46,186 @@ export const GovernorError = {
   10: {message:"ProposalAlreadyExecuted"},
   11: {message:"InvalidProposalState"},
   12: {message:"NotAMember"},
-  13: {message:"ReentrancyDetected"}
+  13: {message:"ReentrancyDetected"},
+  14: {message:"ProposalCanceled"},
+  15: {message:"ProposalNotQueued"},
+  16: {message:"TimelockNotReady"},
+  17: {message:"ProposalExpired"},
+  18: {message:"VotingKeyNotSet"},
+  19: {message:"NonceUsed"},
+  20: {message:"SignatureExpired"},
+  21: {message:"VoteDelegated"},
+  22: {message:"VotingKeyExpired"}
 }
 
 
 export interface GovernanceConfig {
   /**
- * Minimum Mana required to create a proposal
+ * Timelock: seconds a queued proposal must wait before execution
  */
-proposal_threshold: u64;
+execution_delay: u64;
   /**
- * Percentage of votes required for a proposal to pass (e.g. 51)
+ * Seconds after the ETA during which a queued proposal can still be executed
  */
-quorum_percentage: u64;
+grace_period: u64;
   /**
- * Voting delay in seconds (time between proposal creation and voting start)
+ * KRN-03: Prevents single-vote proposal hijacking
  */
-voting_delay: u64;
-  /**
- * Voting period in seconds (duration of voting)
- */
-voting_period: u64;
+participation_threshold: u64;
+  proposal_threshold: u64;
+  quorum_percentage: u64;
+  voting_delay: u64;
+  voting_period: u64;
 }
 
+
 /**
- * Storage keys for the Governor contract
+ * Message signed by a voter's registered key for `cast_vote_by_sig`, encoded as XDR.
+ * Binds the signature to one voter, one Governor deployment and one network.
  */
-export type DataKey = {tag: "Valocracy", values: void} | {tag: "ProposalCount", values: void} | {tag: "Proposal", values: readonly [u64]} | {tag: "Vote", values: readonly [u64, string]} | {tag: "ReentrancyLock", values: void} | {tag: "Config", values: void};
+export interface VoteMessage {
+  contract: string;
+  expiry: u64;
+  network_id: Buffer;
+  nonce: u64;
+  proposal_id: u64;
+  version: u32;
+  vote: VoteType;
+  voter: string;
+}
 
 
 /**
- * An action to execute when proposal succeeds
- */
-export interface Action {
-  /**
- * Arguments as raw vals (simplified)
+ * Key a voter registers for `cast_vote_by_sig`.
  */
-args: Array<any>;
+export interface VotingKey {
   /**
- * Contract to call
+ * Timestamp after which signatures from the key are rejected
  */
-contract_id: string;
-  /**
- * Function name to invoke
- */
-function: string;
+expires_at: u64;
+  public_key: Buffer;
+}
+
+export enum ProposalState {
+  Pending = 0,
+  Active = 1,
+  Succeeded = 2,
+  Defeated = 3,
+  Executed = 4,
+  Canceled = 5,
+  Queued = 6,
+  Expired = 7,
+}
+
+export enum VoteType {
+  Against = 0,
+  For = 1,
+  Abstain = 2,
+}
+
+
+export interface Action {
+  args: Array<any>;
+  contract_id: string;
+  function: string;
 }
 
 
-/**
- * A governance proposal
- */
 export interface Proposal {
   /**
- * Actions to execute on success
- */
-actions: Array<Action>;
-  /**
- * Total votes against
+ * Counts toward participation but not toward approval
  */
-against_votes: u64;
+abstain_votes: u64;
+  actions: Array<Action>;
+  against_votes: u64;
+  canceled: boolean;
   /**
- * Description of the proposal
+ * KRN-03: Snapshot voting power at creation time
  */
-description: string;
+creation_time: u64;
+  description: string;
+  end_time: u64;
   /**
- * Timestamp when voting ends
+ * Timelock: earliest execution time once queued (0 = not queued)
  */
-end_time: u64;
+eta: u64;
+  executed: boolean;
+  for_votes: u64;
   /**
- * Whether the proposal has been executed
+ * Seconds after `eta` during which the proposal can still be executed, fixed when queued
  */
-executed: boolean;
+grace_period: u64;
+  id: u64;
+  proposer: string;
+  start_time: u64;
   /**
- * Total votes in favor
+ * KRN-03: Used for participation percentage
  */
-for_votes: u64;
-  /**
- * Unique proposal ID
- */
-id: u64;
-  /**
- * Address that created the proposal
+total_mana_at_creation: u64;
+}
+
+/**
+ * Storage keys for the Governor contract
  */
-proposer: string;
-  /**
- * Timestamp when voting starts
+export type DataKey = {tag: "Valocracy", values: void} | {tag: "ProposalCount", values: void} | {tag: "Proposal", values: readonly [u64]} | {tag: "Vote", values: readonly [u64, string]} | {tag: "ReentrancyLock", values: void} | {tag: "Config", values: void} | {tag: "VotingKey", values: readonly [string]} | {tag: "UsedNonce", values: readonly [string, u64]};
+
+
+/**
+ * Governance config as stored before the timelock existed.
  */
-start_time: u64;
+export interface GovernanceConfigV1 {
+  participation_threshold: u64;
+  proposal_threshold: u64;
+  quorum_percentage: u64;
+  voting_delay: u64;
+  voting_period: u64;
 }
 
+
 /**
- * Proposal state enum
+ * Proposal as stored before abstentions, cancellation and the timelock existed.
  */
-export enum ProposalState {
-  Pending = 0,
-  Active = 1,
-  Succeeded = 2,
-  Defeated = 3,
-  Executed = 4,
+export interface ProposalV1 {
+  actions: Array<Action>;
+  against_votes: u64;
+  creation_time: u64;
+  description: string;
+  end_time: u64;
+  executed: boolean;
+  for_votes: u64;
+  id: u64;
+  proposer: string;
+  start_time: u64;
+  total_mana_at_creation: u64;
 }
 
 export interface Client {
   /**
-   * Construct and simulate a execute transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Execute a succeeded proposal
+   * Construct and simulate a initialize transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Initialize the Governor contract.
    */
-  execute: ({proposal_id}: {proposal_id: u64}, options?: {
+  initialize: ({valocracy}: {valocracy: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a update_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Update governance configuration (Governor only).
+   */
+  update_config: ({config}: {config: GovernanceConfig}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -171,9 +244,7 @@ export interface Client {
 
   /**
    * Construct and simulate a propose transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Create a new proposal.
-   * 
-   * Open to any member (level > 0 in the Valocracy contract).
+   * Create a new proposal. Any member with sufficient voting power can propose.
    */
   propose: ({proposer, description, actions}: {proposer: string, description: string, actions: Array<Action>}, options?: {
     /**
@@ -193,11 +264,53 @@ export interface Client {
   }) => Promise<AssembledTransaction<Result<u64>>>
 
   /**
-   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Upgrade the contract to a new WASM hash.
-   * Only callable by the governor itself (requires governance proposal).
+   * Construct and simulate a cast_vote transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Cast a vote on a proposal
    */
-  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: {
+  cast_vote: ({voter, proposal_id, vote}: {voter: string, proposal_id: u64, vote: VoteType}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u64>>>
+
+  /**
+   * Construct and simulate a cast_vote_with_reason transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Cast a vote with a reason. The reason is only emitted in an event, not stored.
+   */
+  cast_vote_with_reason: ({voter, proposal_id, vote, reason}: {voter: string, proposal_id: u64, vote: VoteType, reason: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u64>>>
+
+  /**
+   * Construct and simulate a set_voting_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Register the ed25519 key whose signatures `cast_vote_by_sig` accepts for `voter`.
+   * Replaces any key registered before; the key is rejected after `expires_at`.
+   */
+  set_voting_key: ({voter, public_key, expires_at}: {voter: string, public_key: Buffer, expires_at: u64}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -215,10 +328,55 @@ export interface Client {
   }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a cast_vote transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Cast a vote on a proposal
+   * Construct and simulate a remove_voting_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Remove the voting key of `voter`, so only votes it casts itself are accepted.
+   */
+  remove_voting_key: ({voter}: {voter: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a voting_key_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the voting key registered for an account
    */
-  cast_vote: ({voter, proposal_id, support}: {voter: string, proposal_id: u64, support: boolean}, options?: {
+  voting_key_of: ({voter}: {voter: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<VotingKey>>>
+
+  /**
+   * Construct and simulate a cast_vote_by_sig transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Cast a vote relayed on behalf of `voter`.
+   * 
+   * The voter's registered key signs a `VoteMessage` (see `vote_payload`),
+   * so anyone can submit the transaction and pay its fee.
+   */
+  cast_vote_by_sig: ({voter, proposal_id, vote, nonce, expiry, signature}: {voter: string, proposal_id: u64, vote: VoteType, nonce: u64, expiry: u64, signature: Buffer}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -236,10 +394,10 @@ export interface Client {
   }) => Promise<AssembledTransaction<Result<u64>>>
 
   /**
-   * Construct and simulate a has_voted transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Check if an account has voted on a proposal
+   * Construct and simulate a vote_payload transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the exact bytes a voter's key must sign for `cast_vote_by_sig`
    */
-  has_voted: ({proposal_id, voter}: {proposal_id: u64, voter: string}, options?: {
+  vote_payload: ({voter, proposal_id, vote, nonce, expiry}: {voter: string, proposal_id: u64, vote: VoteType, nonce: u64, expiry: u64}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -254,13 +412,14 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<boolean>>
+  }) => Promise<AssembledTransaction<Buffer>>
 
   /**
-   * Construct and simulate a valocracy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get valocracy contract address
+   * Construct and simulate a queue transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Queue a succeeded proposal in the timelock.
+   * The proposal becomes executable once `execution_delay` has elapsed.
    */
-  valocracy: (options?: {
+  queue: ({proposal_id}: {proposal_id: u64}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -275,16 +434,37 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Option<string>>>
+  }) => Promise<AssembledTransaction<Result<u64>>>
 
   /**
-   * Construct and simulate a initialize transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Initialize the Governor contract.
+   * Construct and simulate a execute transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Execute a queued proposal whose timelock has elapsed
+   */
+  execute: ({proposal_id}: {proposal_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a cancel transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Cancel a proposal before it is executed.
    * 
-   * No admin: only stores the Valocracy contract address for membership checks
-   * and voting power queries.
+   * The proposer may cancel while the proposal is still Pending.
+   * Governance (the Governor itself) may cancel at any time before execution.
    */
-  initialize: ({valocracy}: {valocracy: string}, options?: {
+  cancel: ({caller, proposal_id}: {caller: string, proposal_id: u64}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -323,11 +503,10 @@ export interface Client {
   }) => Promise<AssembledTransaction<Option<Proposal>>>
 
   /**
-   * Construct and simulate a update_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Update governance configuration.
-   * Only callable by the Governor (self-governance).
+   * Construct and simulate a get_proposal_state transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current state of a proposal
    */
-  update_config: ({config}: {config: GovernanceConfig}, options?: {
+  get_proposal_state: ({proposal_id}: {proposal_id: u64}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -342,7 +521,7 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<void>>>
+  }) => Promise<AssembledTransaction<Result<ProposalState>>>
 
   /**
    * Construct and simulate a proposal_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -366,10 +545,10 @@ export interface Client {
   }) => Promise<AssembledTransaction<u64>>
 
   /**
-   * Construct and simulate a get_proposal_state transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the current state of a proposal
+   * Construct and simulate a has_voted transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check if an account has voted on a proposal
    */
-  get_proposal_state: ({proposal_id}: {proposal_id: u64}, options?: {
+  has_voted: ({proposal_id, voter}: {proposal_id: u64, voter: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -384,7 +563,49 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<ProposalState>>>
+  }) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a valocracy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get valocracy contract address
+   */
+  valocracy: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Upgrade the contract to a new WASM hash (Governor only).
+   */
+  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
 
 }
 export class Client extends ContractClient {
@@ -404,23 +625,36 @@ export class Client extends ContractClient {
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAAAAABxFeGVjdXRlIGEgc3VjY2VlZGVkIHByb3Bvc2FsAAAAB2V4ZWN1dGUAAAAAAQAAAAAAAAALcHJvcG9zYWxfaWQAAAAABgAAAAEAAAPpAAAD7QAAAAAAAAfQAAAADUdvdmVybm9yRXJyb3IAAAA=",
-        "AAAAAAAAAFFDcmVhdGUgYSBuZXcgcHJvcG9zYWwuCgpPcGVuIHRvIGFueSBtZW1iZXIgKGxldmVsID4gMCBpbiB0aGUgVmFsb2NyYWN5IGNvbnRyYWN0KS4AAAAAAAAHcHJvcG9zZQAAAAADAAAAAAAAAAhwcm9wb3NlcgAAABMAAAAAAAAAC2Rlc2NyaXB0aW9uAAAAABAAAAAAAAAAB2FjdGlvbnMAAAAD6gAAB9AAAAAGQWN0aW9uAAAAAAABAAAD6QAAAAYAAAfQAAAADUdvdmVybm9yRXJyb3IAAAA=",
-        "AAAAAAAAAG1VcGdyYWRlIHRoZSBjb250cmFjdCB0byBhIG5ldyBXQVNNIGhhc2guCk9ubHkgY2FsbGFibGUgYnkgdGhlIGdvdmVybm9yIGl0c2VsZiAocmVxdWlyZXMgZ292ZXJuYW5jZSBwcm9wb3NhbCkuAAAAAAAAB3VwZ3JhZGUAAAAAAQAAAAAAAAANbmV3X3dhc21faGFzaAAAAAAAA+4AAAAgAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAANR292ZXJub3JFcnJvcgAAAA==",
-        "AAAAAAAAABlDYXN0IGEgdm90ZSBvbiBhIHByb3Bvc2FsAAAAAAAACWNhc3Rfdm90ZQAAAAAAAAMAAAAAAAAABXZvdGVyAAAAAAAAEwAAAAAAAAALcHJvcG9zYWxfaWQAAAAABgAAAAAAAAAHc3VwcG9ydAAAAAABAAAAAQAAA+kAAAAGAAAH0AAAAA1Hb3Zlcm5vckVycm9yAAAA",
-        "AAAAAAAAACtDaGVjayBpZiBhbiBhY2NvdW50IGhhcyB2b3RlZCBvbiBhIHByb3Bvc2FsAAAAAAloYXNfdm90ZWQAAAAAAAACAAAAAAAAAAtwcm9wb3NhbF9pZAAAAAAGAAAAAAAAAAV2b3RlcgAAAAAAABMAAAABAAAAAQ==",
-        "AAAAAAAAAB5HZXQgdmFsb2NyYWN5IGNvbnRyYWN0IGFkZHJlc3MAAAAAAAl2YWxvY3JhY3kAAAAAAAAAAAAAAQAAA+gAAAAT",
-        "AAAAAAAAAIdJbml0aWFsaXplIHRoZSBHb3Zlcm5vciBjb250cmFjdC4KCk5vIGFkbWluOiBvbmx5IHN0b3JlcyB0aGUgVmFsb2NyYWN5IGNvbnRyYWN0IGFkZHJlc3MgZm9yIG1lbWJlcnNoaXAgY2hlY2tzCmFuZCB2b3RpbmcgcG93ZXIgcXVlcmllcy4AAAAACmluaXRpYWxpemUAAAAAAAEAAAAAAAAACXZhbG9jcmFjeQAAAAAAABMAAAABAAAD6QAAA+0AAAAAAAAH0AAAAA1Hb3Zlcm5vckVycm9yAAAA",
+      new ContractSpec([ "AAAAAAAAACFJbml0aWFsaXplIHRoZSBHb3Zlcm5vciBjb250cmFjdC4AAAAAAAAKaW5pdGlhbGl6ZQAAAAAAAQAAAAAAAAAJdmFsb2NyYWN5AAAAAAAAEwAAAAEAAAPpAAAAAgAAB9AAAAANR292ZXJub3JFcnJvcgAAAA==",
+        "AAAAAAAAADBVcGRhdGUgZ292ZXJuYW5jZSBjb25maWd1cmF0aW9uIChHb3Zlcm5vciBvbmx5KS4AAAANdXBkYXRlX2NvbmZpZwAAAAAAAAEAAAAAAAAABmNvbmZpZwAAAAAH0AAAABBHb3Zlcm5hbmNlQ29uZmlnAAAAAQAAA+kAAAACAAAH0AAAAA1Hb3Zlcm5vckVycm9yAAAA",
+        "AAAAAAAAAEtDcmVhdGUgYSBuZXcgcHJvcG9zYWwuIEFueSBtZW1iZXIgd2l0aCBzdWZmaWNpZW50IHZvdGluZyBwb3dlciBjYW4gcHJvcG9zZS4AAAAAB3Byb3Bvc2UAAAAAAwAAAAAAAAAIcHJvcG9zZXIAAAATAAAAAAAAAAtkZXNjcmlwdGlvbgAAAAAQAAAAAAAAAAdhY3Rpb25zAAAAA+oAAAfQAAAABkFjdGlvbgAAAAAAAQAAA+kAAAAGAAAH0AAAAA1Hb3Zlcm5vckVycm9yAAAA",
+        "AAAAAAAAABlDYXN0IGEgdm90ZSBvbiBhIHByb3Bvc2FsAAAAAAAACWNhc3Rfdm90ZQAAAAAAAAMAAAAAAAAABXZvdGVyAAAAAAAAEwAAAAAAAAALcHJvcG9zYWxfaWQAAAAABgAAAAAAAAAEdm90ZQAAB9AAAAAIVm90ZVR5cGUAAAABAAAD6QAAAAYAAAfQAAAADUdvdmVybm9yRXJyb3IAAAA=",
+        "AAAAAAAAAE5DYXN0IGEgdm90ZSB3aXRoIGEgcmVhc29uLiBUaGUgcmVhc29uIGlzIG9ubHkgZW1pdHRlZCBpbiBhbiBldmVudCwgbm90IHN0b3JlZC4AAAAAABVjYXN0X3ZvdGVfd2l0aF9yZWFzb24AAAAAAAAEAAAAAAAAAAV2b3RlcgAAAAAAABMAAAAAAAAAC3Byb3Bvc2FsX2lkAAAAAAYAAAAAAAAABHZvdGUAAAfQAAAACFZvdGVUeXBlAAAAAAAAAAZyZWFzb24AAAAAABAAAAABAAAD6QAAAAYAAAfQAAAADUdvdmVybm9yRXJyb3IAAAA=",
+        "AAAAAAAAAJ1SZWdpc3RlciB0aGUgZWQyNTUxOSBrZXkgd2hvc2Ugc2lnbmF0dXJlcyBgY2FzdF92b3RlX2J5X3NpZ2AgYWNjZXB0cyBmb3IgYHZvdGVyYC4KUmVwbGFjZXMgYW55IGtleSByZWdpc3RlcmVkIGJlZm9yZTsgdGhlIGtleSBpcyByZWplY3RlZCBhZnRlciBgZXhwaXJlc19hdGAuAAAAAAAADnNldF92b3Rpbmdfa2V5AAAAAAADAAAAAAAAAAV2b3RlcgAAAAAAABMAAAAAAAAACnB1YmxpY19rZXkAAAAAA+4AAAAgAAAAAAAAAApleHBpcmVzX2F0AAAAAAAGAAAAAQAAA+kAAAACAAAH0AAAAA1Hb3Zlcm5vckVycm9yAAAA",
+        "AAAAAAAAAE1SZW1vdmUgdGhlIHZvdGluZyBrZXkgb2YgYHZvdGVyYCwgc28gb25seSB2b3RlcyBpdCBjYXN0cyBpdHNlbGYgYXJlIGFjY2VwdGVkLgAAAAAAABFyZW1vdmVfdm90aW5nX2tleQAAAAAAAAEAAAAAAAAABXZvdGVyAAAAAAAAEwAAAAEAAAPpAAAAAgAAB9AAAAANR292ZXJub3JFcnJvcgAAAA==",
+        "AAAAAAAAACxHZXQgdGhlIHZvdGluZyBrZXkgcmVnaXN0ZXJlZCBmb3IgYW4gYWNjb3VudAAAAA12b3Rpbmdfa2V5X29mAAAAAAAAAQAAAAAAAAAFdm90ZXIAAAAAAAATAAAAAQAAA+gAAAfQAAAACVZvdGluZ0tleQAAAA==",
+        "AAAAAAAAAKdDYXN0IGEgdm90ZSByZWxheWVkIG9uIGJlaGFsZiBvZiBgdm90ZXJgLgoKVGhlIHZvdGVyJ3MgcmVnaXN0ZXJlZCBrZXkgc2lnbnMgYSBgVm90ZU1lc3NhZ2VgIChzZWUgYHZvdGVfcGF5bG9hZGApLApzbyBhbnlvbmUgY2FuIHN1Ym1pdCB0aGUgdHJhbnNhY3Rpb24gYW5kIHBheSBpdHMgZmVlLgAAAAAQY2FzdF92b3RlX2J5X3NpZwAAAAYAAAAAAAAABXZvdGVyAAAAAAAAEwAAAAAAAAALcHJvcG9zYWxfaWQAAAAABgAAAAAAAAAEdm90ZQAAB9AAAAAIVm90ZVR5cGUAAAAAAAAABW5vbmNlAAAAAAAABgAAAAAAAAAGZXhwaXJ5AAAAAAAGAAAAAAAAAAlzaWduYXR1cmUAAAAAAAPuAAAAQAAAAAEAAAPpAAAABgAAB9AAAAANR292ZXJub3JFcnJvcgAAAA==",
+        "AAAAAAAAAEJHZXQgdGhlIGV4YWN0IGJ5dGVzIGEgdm90ZXIncyBrZXkgbXVzdCBzaWduIGZvciBgY2FzdF92b3RlX2J5X3NpZ2AAAAAAAAx2b3RlX3BheWxvYWQAAAAFAAAAAAAAAAV2b3RlcgAAAAAAABMAAAAAAAAAC3Byb3Bvc2FsX2lkAAAAAAYAAAAAAAAABHZvdGUAAAfQAAAACFZvdGVUeXBlAAAAAAAAAAVub25jZQAAAAAAAAYAAAAAAAAABmV4cGlyeQAAAAAABgAAAAEAAAAO",
+        "AAAAAAAAAG9RdWV1ZSBhIHN1Y2NlZWRlZCBwcm9wb3NhbCBpbiB0aGUgdGltZWxvY2suClRoZSBwcm9wb3NhbCBiZWNvbWVzIGV4ZWN1dGFibGUgb25jZSBgZXhlY3V0aW9uX2RlbGF5YCBoYXMgZWxhcHNlZC4AAAAABXF1ZXVlAAAAAAAAAQAAAAAAAAALcHJvcG9zYWxfaWQAAAAABgAAAAEAAAPpAAAABgAAB9AAAAANR292ZXJub3JFcnJvcgAAAA==",
+        "AAAAAAAAADRFeGVjdXRlIGEgcXVldWVkIHByb3Bvc2FsIHdob3NlIHRpbWVsb2NrIGhhcyBlbGFwc2VkAAAAB2V4ZWN1dGUAAAAAAQAAAAAAAAALcHJvcG9zYWxfaWQAAAAABgAAAAEAAAPpAAAAAgAAB9AAAAANR292ZXJub3JFcnJvcgAAAA==",
+        "AAAAAAAAALBDYW5jZWwgYSBwcm9wb3NhbCBiZWZvcmUgaXQgaXMgZXhlY3V0ZWQuCgpUaGUgcHJvcG9zZXIgbWF5IGNhbmNlbCB3aGlsZSB0aGUgcHJvcG9zYWwgaXMgc3RpbGwgUGVuZGluZy4KR292ZXJuYW5jZSAodGhlIEdvdmVybm9yIGl0c2VsZikgbWF5IGNhbmNlbCBhdCBhbnkgdGltZSBiZWZvcmUgZXhlY3V0aW9uLgAAAAZjYW5jZWwAAAAAAAIAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAALcHJvcG9zYWxfaWQAAAAABgAAAAEAAAPpAAAAAgAAB9AAAAANR292ZXJub3JFcnJvcgAAAA==",
         "AAAAAAAAABRHZXQgYSBwcm9wb3NhbCBieSBJRAAAAAxnZXRfcHJvcG9zYWwAAAABAAAAAAAAAAtwcm9wb3NhbF9pZAAAAAAGAAAAAQAAA+gAAAfQAAAACFByb3Bvc2Fs",
-        "AAAAAAAAAFFVcGRhdGUgZ292ZXJuYW5jZSBjb25maWd1cmF0aW9uLgpPbmx5IGNhbGxhYmxlIGJ5IHRoZSBHb3Zlcm5vciAoc2VsZi1nb3Zlcm5hbmNlKS4AAAAAAAANdXBkYXRlX2NvbmZpZwAAAAAAAAEAAAAAAAAABmNvbmZpZwAAAAAH0AAAABBHb3Zlcm5hbmNlQ29uZmlnAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAANR292ZXJub3JFcnJvcgAAAA==",
-        "AAAAAAAAABtHZXQgdGhlIG51bWJlciBvZiBwcm9wb3NhbHMAAAAADnByb3Bvc2FsX2NvdW50AAAAAAAAAAAAAQAAAAY=",
-        "AAAABAAAAAAAAAAAAAAADUdvdmVybm9yRXJyb3IAAAAAAAANAAAAAAAAABJBbHJlYWR5SW5pdGlhbGl6ZWQAAAAAAAEAAAAAAAAADk5vdEluaXRpYWxpemVkAAAAAAACAAAAAAAAAA1Ob3RBdXRob3JpemVkAAAAAAAAAwAAAAAAAAAQUHJvcG9zYWxOb3RGb3VuZAAAAAQAAAAAAAAAEFZvdGluZ05vdFN0YXJ0ZWQAAAAFAAAAAAAAAAtWb3RpbmdFbmRlZAAAAAAGAAAAAAAAAAxBbHJlYWR5Vm90ZWQAAAAHAAAAAAAAAA1Ob1ZvdGluZ1Bvd2VyAAAAAAAACAAAAAAAAAAUUHJvcG9zYWxOb3RTdWNjZWVkZWQAAAAJAAAAAAAAABdQcm9wb3NhbEFscmVhZHlFeGVjdXRlZAAAAAAKAAAAAAAAABRJbnZhbGlkUHJvcG9zYWxTdGF0ZQAAAAsAAAAAAAAACk5vdEFNZW1iZXIAAAAAAAwAAAAAAAAAElJlZW50cmFuY3lEZXRlY3RlZAAAAAAADQ==",
         "AAAAAAAAACNHZXQgdGhlIGN1cnJlbnQgc3RhdGUgb2YgYSBwcm9wb3NhbAAAAAASZ2V0X3Byb3Bvc2FsX3N0YXRlAAAAAAABAAAAAAAAAAtwcm9wb3NhbF9pZAAAAAAGAAAAAQAAA+kAAAfQAAAADVByb3Bvc2FsU3RhdGUAAAAAAAfQAAAADUdvdmVybm9yRXJyb3IAAAA=",
-        "AAAAAQAAAAAAAAAAAAAAEEdvdmVybmFuY2VDb25maWcAAAAEAAAAKk1pbmltdW0gTWFuYSByZXF1aXJlZCB0byBjcmVhdGUgYSBwcm9wb3NhbAAAAAAAEnByb3Bvc2FsX3RocmVzaG9sZAAAAAAABgAAAD1QZXJjZW50YWdlIG9mIHZvdGVzIHJlcXVpcmVkIGZvciBhIHByb3Bvc2FsIHRvIHBhc3MgKGUuZy4gNTEpAAAAAAAAEXF1b3J1bV9wZXJjZW50YWdlAAAAAAAABgAAAElWb3RpbmcgZGVsYXkgaW4gc2Vjb25kcyAodGltZSBiZXR3ZWVuIHByb3Bvc2FsIGNyZWF0aW9uIGFuZCB2b3Rpbmcgc3RhcnQpAAAAAAAADHZvdGluZ19kZWxheQAAAAYAAAAtVm90aW5nIHBlcmlvZCBpbiBzZWNvbmRzIChkdXJhdGlvbiBvZiB2b3RpbmcpAAAAAAAADXZvdGluZ19wZXJpb2QAAAAAAAAG",
-        "AAAAAgAAACZTdG9yYWdlIGtleXMgZm9yIHRoZSBHb3Zlcm5vciBjb250cmFjdAAAAAAAAAAAAAdEYXRhS2V5AAAAAAYAAAAAAAAAGlZhbG9jcmFjeSBjb250cmFjdCBhZGRyZXNzAAAAAAAJVmFsb2NyYWN5AAAAAAAAAAAAABlUb3RhbCBudW1iZXIgb2YgcHJvcG9zYWxzAAAAAAAADVByb3Bvc2FsQ291bnQAAAAAAAABAAAAE1Byb3Bvc2FsIGRhdGEgYnkgSUQAAAAACFByb3Bvc2FsAAAAAQAAAAYAAAABAAAAM1ZvdGUgcmVjb3JkOiAocHJvcG9zYWxfaWQsIHZvdGVyKSAtPiBib29sIChzdXBwb3J0KQAAAAAEVm90ZQAAAAIAAAAGAAAAEwAAAAAAAAAPUmVlbnRyYW5jeSBsb2NrAAAAAA5SZWVudHJhbmN5TG9jawAAAAAAAAAAABhHb3Zlcm5hbmNlIGNvbmZpZ3VyYXRpb24AAAAGQ29uZmlnAAA=",
-        "AAAAAQAAACtBbiBhY3Rpb24gdG8gZXhlY3V0ZSB3aGVuIHByb3Bvc2FsIHN1Y2NlZWRzAAAAAAAAAAAGQWN0aW9uAAAAAAADAAAAIkFyZ3VtZW50cyBhcyByYXcgdmFscyAoc2ltcGxpZmllZCkAAAAAAARhcmdzAAAD6gAAAAAAAAAQQ29udHJhY3QgdG8gY2FsbAAAAAtjb250cmFjdF9pZAAAAAATAAAAF0Z1bmN0aW9uIG5hbWUgdG8gaW52b2tlAAAAAAhmdW5jdGlvbgAAABE=",
-        "AAAAAQAAABVBIGdvdmVybmFuY2UgcHJvcG9zYWwAAAAAAAAAAAAACFByb3Bvc2FsAAAACQAAAB1BY3Rpb25zIHRvIGV4ZWN1dGUgb24gc3VjY2VzcwAAAAAAAAdhY3Rpb25zAAAAA+oAAAfQAAAABkFjdGlvbgAAAAAAE1RvdGFsIHZvdGVzIGFnYWluc3QAAAAADWFnYWluc3Rfdm90ZXMAAAAAAAAGAAAAG0Rlc2NyaXB0aW9uIG9mIHRoZSBwcm9wb3NhbAAAAAALZGVzY3JpcHRpb24AAAAAEAAAABpUaW1lc3RhbXAgd2hlbiB2b3RpbmcgZW5kcwAAAAAACGVuZF90aW1lAAAABgAAACZXaGV0aGVyIHRoZSBwcm9wb3NhbCBoYXMgYmVlbiBleGVjdXRlZAAAAAAACGV4ZWN1dGVkAAAAAQAAABRUb3RhbCB2b3RlcyBpbiBmYXZvcgAAAAlmb3Jfdm90ZXMAAAAAAAAGAAAAElVuaXF1ZSBwcm9wb3NhbCBJRAAAAAAAAmlkAAAAAAAGAAAAIUFkZHJlc3MgdGhhdCBjcmVhdGVkIHRoZSBwcm9wb3NhbAAAAAAAAAhwcm9wb3NlcgAAABMAAAAcVGltZXN0YW1wIHdoZW4gdm90aW5nIHN0YXJ0cwAAAApzdGFydF90aW1lAAAAAAAG",
-        "AAAAAwAAABNQcm9wb3NhbCBzdGF0ZSBlbnVtAAAAAAAAAAANUHJvcG9zYWxTdGF0ZQAAAAAAAAUAAAAbV2FpdGluZyBmb3Igdm90aW5nIHRvIHN0YXJ0AAAAAAdQZW5kaW5nAAAAAAAAAAAQVm90aW5nIGlzIGFjdGl2ZQAAAAZBY3RpdmUAAAAAAAEAAAAfUHJvcG9zYWwgc3VjY2VlZGVkIChxdW9ydW0gbWV0KQAAAAAJU3VjY2VlZGVkAAAAAAAAAgAAACJQcm9wb3NhbCBkZWZlYXRlZCAocXVvcnVtIG5vdCBtZXQpAAAAAAAIRGVmZWF0ZWQAAAADAAAAGlByb3Bvc2FsIGhhcyBiZWVuIGV4ZWN1dGVkAAAAAAAIRXhlY3V0ZWQAAAAE" ]),
+        "AAAAAAAAABtHZXQgdGhlIG51bWJlciBvZiBwcm9wb3NhbHMAAAAADnByb3Bvc2FsX2NvdW50AAAAAAAAAAAAAQAAAAY=",
+        "AAAAAAAAACtDaGVjayBpZiBhbiBhY2NvdW50IGhhcyB2b3RlZCBvbiBhIHByb3Bvc2FsAAAAAAloYXNfdm90ZWQAAAAAAAACAAAAAAAAAAtwcm9wb3NhbF9pZAAAAAAGAAAAAAAAAAV2b3RlcgAAAAAAABMAAAABAAAAAQ==",
+        "AAAAAAAAAB5HZXQgdmFsb2NyYWN5IGNvbnRyYWN0IGFkZHJlc3MAAAAAAAl2YWxvY3JhY3kAAAAAAAAAAAAAAQAAA+gAAAAT",
+        "AAAAAAAAADhVcGdyYWRlIHRoZSBjb250cmFjdCB0byBhIG5ldyBXQVNNIGhhc2ggKEdvdmVybm9yIG9ubHkpLgAAAAd1cGdyYWRlAAAAAAEAAAAAAAAADW5ld193YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAEAAAPpAAAAAgAAB9AAAAANR292ZXJub3JFcnJvcgAAAA==",
+        "AAAABAAAAAAAAAAAAAAADUdvdmVybm9yRXJyb3IAAAAAAAAWAAAAAAAAABJBbHJlYWR5SW5pdGlhbGl6ZWQAAAAAAAEAAAAAAAAADk5vdEluaXRpYWxpemVkAAAAAAACAAAAAAAAAA1Ob3RBdXRob3JpemVkAAAAAAAAAwAAAAAAAAAQUHJvcG9zYWxOb3RGb3VuZAAAAAQAAAAAAAAAEFZvdGluZ05vdFN0YXJ0ZWQAAAAFAAAAAAAAAAtWb3RpbmdFbmRlZAAAAAAGAAAAAAAAAAxBbHJlYWR5Vm90ZWQAAAAHAAAAAAAAAA1Ob1ZvdGluZ1Bvd2VyAAAAAAAACAAAAAAAAAAUUHJvcG9zYWxOb3RTdWNjZWVkZWQAAAAJAAAAAAAAABdQcm9wb3NhbEFscmVhZHlFeGVjdXRlZAAAAAAKAAAAAAAAABRJbnZhbGlkUHJvcG9zYWxTdGF0ZQAAAAsAAAAAAAAACk5vdEFNZW1iZXIAAAAAAAwAAAAAAAAAElJlZW50cmFuY3lEZXRlY3RlZAAAAAAADQAAAAAAAAAQUHJvcG9zYWxDYW5jZWxlZAAAAA4AAAAAAAAAEVByb3Bvc2FsTm90UXVldWVkAAAAAAAADwAAAAAAAAAQVGltZWxvY2tOb3RSZWFkeQAAABAAAAAAAAAAD1Byb3Bvc2FsRXhwaXJlZAAAAAARAAAAAAAAAA9Wb3RpbmdLZXlOb3RTZXQAAAAAEgAAAAAAAAAJTm9uY2VVc2VkAAAAAAAAEwAAAAAAAAAQU2lnbmF0dXJlRXhwaXJlZAAAABQAAAAAAAAADVZvdGVEZWxlZ2F0ZWQAAAAAAAAVAAAAAAAAABBWb3RpbmdLZXlFeHBpcmVkAAAAFg==",
+        "AAAAAQAAAAAAAAAAAAAAEEdvdmVybmFuY2VDb25maWcAAAAHAAAAPlRpbWVsb2NrOiBzZWNvbmRzIGEgcXVldWVkIHByb3Bvc2FsIG11c3Qgd2FpdCBiZWZvcmUgZXhlY3V0aW9uAAAAAAAPZXhlY3V0aW9uX2RlbGF5AAAAAAYAAABKU2Vjb25kcyBhZnRlciB0aGUgRVRBIGR1cmluZyB3aGljaCBhIHF1ZXVlZCBwcm9wb3NhbCBjYW4gc3RpbGwgYmUgZXhlY3V0ZWQAAAAAAAxncmFjZV9wZXJpb2QAAAAGAAAAL0tSTi0wMzogUHJldmVudHMgc2luZ2xlLXZvdGUgcHJvcG9zYWwgaGlqYWNraW5nAAAAABdwYXJ0aWNpcGF0aW9uX3RocmVzaG9sZAAAAAAGAAAAAAAAABJwcm9wb3NhbF90aHJlc2hvbGQAAAAAAAYAAAAAAAAAEXF1b3J1bV9wZXJjZW50YWdlAAAAAAAABgAAAAAAAAAMdm90aW5nX2RlbGF5AAAABgAAAAAAAAANdm90aW5nX3BlcmlvZAAAAAAAAAY=",
+        "AAAAAQAAAJ1NZXNzYWdlIHNpZ25lZCBieSBhIHZvdGVyJ3MgcmVnaXN0ZXJlZCBrZXkgZm9yIGBjYXN0X3ZvdGVfYnlfc2lnYCwgZW5jb2RlZCBhcyBYRFIuCkJpbmRzIHRoZSBzaWduYXR1cmUgdG8gb25lIHZvdGVyLCBvbmUgR292ZXJub3IgZGVwbG95bWVudCBhbmQgb25lIG5ldHdvcmsuAAAAAAAAAAAAAAtWb3RlTWVzc2FnZQAAAAAIAAAAAAAAAAhjb250cmFjdAAAABMAAAAAAAAABmV4cGlyeQAAAAAABgAAAAAAAAAKbmV0d29ya19pZAAAAAAD7gAAACAAAAAAAAAABW5vbmNlAAAAAAAABgAAAAAAAAALcHJvcG9zYWxfaWQAAAAABgAAAAAAAAAHdmVyc2lvbgAAAAAEAAAAAAAAAAR2b3RlAAAH0AAAAAhWb3RlVHlwZQAAAAAAAAAFdm90ZXIAAAAAAAAT",
+        "AAAAAQAAAC1LZXkgYSB2b3RlciByZWdpc3RlcnMgZm9yIGBjYXN0X3ZvdGVfYnlfc2lnYC4AAAAAAAAAAAAACVZvdGluZ0tleQAAAAAAAAIAAAA6VGltZXN0YW1wIGFmdGVyIHdoaWNoIHNpZ25hdHVyZXMgZnJvbSB0aGUga2V5IGFyZSByZWplY3RlZAAAAAAACmV4cGlyZXNfYXQAAAAAAAYAAAAAAAAACnB1YmxpY19rZXkAAAAAA+4AAAAg",
+        "AAAAAwAAAAAAAAAAAAAADVByb3Bvc2FsU3RhdGUAAAAAAAAIAAAAAAAAAAdQZW5kaW5nAAAAAAAAAAAAAAAABkFjdGl2ZQAAAAAAAQAAAAAAAAAJU3VjY2VlZGVkAAAAAAAAAgAAAAAAAAAIRGVmZWF0ZWQAAAADAAAAAAAAAAhFeGVjdXRlZAAAAAQAAAAAAAAACENhbmNlbGVkAAAABQAAAAAAAAAGUXVldWVkAAAAAAAGAAAAAAAAAAdFeHBpcmVkAAAAAAc=",
+        "AAAAAwAAAAAAAAAAAAAACFZvdGVUeXBlAAAAAwAAAAAAAAAHQWdhaW5zdAAAAAAAAAAAAAAAAANGb3IAAAAAAQAAAAAAAAAHQWJzdGFpbgAAAAAC",
+        "AAAAAQAAAAAAAAAAAAAABkFjdGlvbgAAAAAAAwAAAAAAAAAEYXJncwAAA+oAAAAAAAAAAAAAAAtjb250cmFjdF9pZAAAAAATAAAAAAAAAAhmdW5jdGlvbgAAABE=",
+        "AAAAAQAAAAAAAAAAAAAACFByb3Bvc2FsAAAADwAAADNDb3VudHMgdG93YXJkIHBhcnRpY2lwYXRpb24gYnV0IG5vdCB0b3dhcmQgYXBwcm92YWwAAAAADWFic3RhaW5fdm90ZXMAAAAAAAAGAAAAAAAAAAdhY3Rpb25zAAAAA+oAAAfQAAAABkFjdGlvbgAAAAAAAAAAAA1hZ2FpbnN0X3ZvdGVzAAAAAAAABgAAAAAAAAAIY2FuY2VsZWQAAAABAAAALktSTi0wMzogU25hcHNob3Qgdm90aW5nIHBvd2VyIGF0IGNyZWF0aW9uIHRpbWUAAAAAAA1jcmVhdGlvbl90aW1lAAAAAAAABgAAAAAAAAALZGVzY3JpcHRpb24AAAAAEAAAAAAAAAAIZW5kX3RpbWUAAAAGAAAAPlRpbWVsb2NrOiBlYXJsaWVzdCBleGVjdXRpb24gdGltZSBvbmNlIHF1ZXVlZCAoMCA9IG5vdCBxdWV1ZWQpAAAAAAADZXRhAAAAAAYAAAAAAAAACGV4ZWN1dGVkAAAAAQAAAAAAAAAJZm9yX3ZvdGVzAAAAAAAABgAAAFZTZWNvbmRzIGFmdGVyIGBldGFgIGR1cmluZyB3aGljaCB0aGUgcHJvcG9zYWwgY2FuIHN0aWxsIGJlIGV4ZWN1dGVkLCBmaXhlZCB3aGVuIHF1ZXVlZAAAAAAADGdyYWNlX3BlcmlvZAAAAAYAAAAAAAAAAmlkAAAAAAAGAAAAAAAAAAhwcm9wb3NlcgAAABMAAAAAAAAACnN0YXJ0X3RpbWUAAAAAAAYAAAApS1JOLTAzOiBVc2VkIGZvciBwYXJ0aWNpcGF0aW9uIHBlcmNlbnRhZ2UAAAAAAAAWdG90YWxfbWFuYV9hdF9jcmVhdGlvbgAAAAAABg==",
+        "AAAAAgAAACZTdG9yYWdlIGtleXMgZm9yIHRoZSBHb3Zlcm5vciBjb250cmFjdAAAAAAAAAAAAAdEYXRhS2V5AAAAAAgAAAAAAAAAAAAAAAlWYWxvY3JhY3kAAAAAAAAAAAAAAAAAAA1Qcm9wb3NhbENvdW50AAAAAAAAAQAAAAAAAAAIUHJvcG9zYWwAAAABAAAABgAAAAEAAAAAAAAABFZvdGUAAAACAAAABgAAABMAAAAAAAAAAAAAAA5SZWVudHJhbmN5TG9jawAAAAAAAAAAAAAAAAAGQ29uZmlnAAAAAAABAAAAOVZvdGVyIGFkZHJlc3MgLT4gZWQyNTUxOSBrZXkgYWNjZXB0ZWQgYnkgY2FzdF92b3RlX2J5X3NpZwAAAAAAAAlWb3RpbmdLZXkAAAAAAAABAAAAEwAAAAEAAAAAAAAACVVzZWROb25jZQAAAAAAAAIAAAATAAAABg==",
+        "AAAAAQAAADhHb3Zlcm5hbmNlIGNvbmZpZyBhcyBzdG9yZWQgYmVmb3JlIHRoZSB0aW1lbG9jayBleGlzdGVkLgAAAAAAAAASR292ZXJuYW5jZUNvbmZpZ1YxAAAAAAAFAAAAAAAAABdwYXJ0aWNpcGF0aW9uX3RocmVzaG9sZAAAAAAGAAAAAAAAABJwcm9wb3NhbF90aHJlc2hvbGQAAAAAAAYAAAAAAAAAEXF1b3J1bV9wZXJjZW50YWdlAAAAAAAABgAAAAAAAAAMdm90aW5nX2RlbGF5AAAABgAAAAAAAAANdm90aW5nX3BlcmlvZAAAAAAAAAY=",
+        "AAAAAQAAAE1Qcm9wb3NhbCBhcyBzdG9yZWQgYmVmb3JlIGFic3RlbnRpb25zLCBjYW5jZWxsYXRpb24gYW5kIHRoZSB0aW1lbG9jayBleGlzdGVkLgAAAAAAAAAAAAAKUHJvcG9zYWxWMQAAAAAACwAAAAAAAAAHYWN0aW9ucwAAAAPqAAAH0AAAAAZBY3Rpb24AAAAAAAAAAAANYWdhaW5zdF92b3RlcwAAAAAAAAYAAAAAAAAADWNyZWF0aW9uX3RpbWUAAAAAAAAGAAAAAAAAAAtkZXNjcmlwdGlvbgAAAAAQAAAAAAAAAAhlbmRfdGltZQAAAAYAAAAAAAAACGV4ZWN1dGVkAAAAAQAAAAAAAAAJZm9yX3ZvdGVzAAAAAAAABgAAAAAAAAACaWQAAAAAAAYAAAAAAAAACHByb3Bvc2VyAAAAEwAAAAAAAAAKc3RhcnRfdGltZQAAAAAABgAAAAAAAAAWdG90YWxfbWFuYV9hdF9jcmVhdGlvbgAAAAAABg==" ]),
       options
     )
   }
@@ -44,7 +44,14 @@ export const TreasuryError = {
   8: {message:"MathOverflow"},
   9: {message:"LabNotFound"},
   10: {message:"LabNotActive"},
-  11: {message:"InsufficientClaimable"}
+  11: {message:"InsufficientClaimable"},
+  12: {message:"LabBudgetExceeded"},
+  13: {message:"AlreadyApproved"},
+  14: {message:"InvalidVesting"},
+  15: {message:"TooManySponsors"},
+  16: {message:"LabNotLegacy"},
+  17: {message:"LabNotCancelled"},
+  18: {message:"ScholarshipNotFound"}
 }
 
 
@@ -428,9 +435,8 @@ export interface Client {
 
   /**
    * Construct and simulate a get_claimable_balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get claimable balance for a member
-   * 
-   * Returns the amount of scholarship funds available for withdrawal.
+   * Get claimable scholarship balance across all labs, including any balance
+   * pooled before scholarships were tracked per lab.
    */
   get_claimable_balance: ({member}: {member: string}, options?: {
     /**
@@ -447,7 +453,7 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<i128>>
+  }) => Promise<AssembledTransaction<Result<i128>>>
 
 }
 export class Client extends ContractClient {
@@ -480,11 +486,11 @@ export class Client extends ContractClient {
         "AAAAAAAAAEVHZXQgdG90YWwgYXNzZXRzIGluIHRoZSB0cmVhc3VyeSBieSBxdWVyeWluZyB0aGUgYWN0dWFsIHRva2VuIGJhbGFuY2UAAAAAAAAMdG90YWxfYXNzZXRzAAAAAAAAAAEAAAAL",
         "AAAAAAAAABxHZXQgdG90YWwgc2hhcmVzIG91dHN0YW5kaW5nAAAADHRvdGFsX3NoYXJlcwAAAAAAAAABAAAACw==",
         "AAAAAAAAAF1VcGRhdGUgdGhlIGdvdmVybm9yIGNvbnRyYWN0IGFkZHJlc3MgKG1pZ3JhdGlvbiBwYXRoKS4KT25seSBjYWxsYWJsZSBieSB0aGUgY3VycmVudCBnb3Zlcm5vci4AAAAAAAAPdXBkYXRlX2dvdmVybm9yAAAAAAEAAAAAAAAADG5ld19nb3Zlcm5vcgAAABMAAAABAAAD6QAAA+0AAAAAAAAH0AAAAA1UcmVhc3VyeUVycm9yAAAA",
-        "AAAABAAAAAAAAAAAAAAADVRyZWFzdXJ5RXJyb3IAAAAAAAALAAAAAAAAABJBbHJlYWR5SW5pdGlhbGl6ZWQAAAAAAAEAAAAAAAAADk5vdEluaXRpYWxpemVkAAAAAAACAAAAAAAAAA1Ob3RBdXRob3JpemVkAAAAAAAAAwAAAAAAAAASSW5zdWZmaWNpZW50U2hhcmVzAAAAAAAEAAAAAAAAABJJbnN1ZmZpY2llbnRBc3NldHMAAAAAAAUAAAAAAAAAClplcm9BbW91bnQAAAAAAAYAAAAAAAAAElJlZW50cmFuY3lEZXRlY3RlZAAAAAAABwAAAAAAAAAMTWF0aE92ZXJmbG93AAAACAAAAAAAAAALTGFiTm90Rm91bmQAAAAACQAAAAAAAAAMTGFiTm90QWN0aXZlAAAACgAAAAAAAAAVSW5zdWZmaWNpZW50Q2xhaW1hYmxlAAAAAAAACw==",
+        "AAAABAAAAAAAAAAAAAAADVRyZWFzdXJ5RXJyb3IAAAAAAAASAAAAAAAAABJBbHJlYWR5SW5pdGlhbGl6ZWQAAAAAAAEAAAAAAAAADk5vdEluaXRpYWxpemVkAAAAAAACAAAAAAAAAA1Ob3RBdXRob3JpemVkAAAAAAAAAwAAAAAAAAASSW5zdWZmaWNpZW50U2hhcmVzAAAAAAAEAAAAAAAAABJJbnN1ZmZpY2llbnRBc3NldHMAAAAAAAUAAAAAAAAAClplcm9BbW91bnQAAAAAAAYAAAAAAAAAElJlZW50cmFuY3lEZXRlY3RlZAAAAAAABwAAAAAAAAAMTWF0aE92ZXJmbG93AAAACAAAAAAAAAALTGFiTm90Rm91bmQAAAAACQAAAAAAAAAMTGFiTm90QWN0aXZlAAAACgAAAAAAAAAVSW5zdWZmaWNpZW50Q2xhaW1hYmxlAAAAAAAACwAAAAAAAAARTGFiQnVkZ2V0RXhjZWVkZWQAAAAAAAAMAAAAAAAAAA9BbHJlYWR5QXBwcm92ZWQAAAAADQAAAAAAAAAOSW52YWxpZFZlc3RpbmcAAAAAAA4AAAAAAAAAD1Rvb01hbnlTcG9uc29ycwAAAAAPAAAAAAAAAAxMYWJOb3RMZWdhY3kAAAAQAAAAAAAAAA9MYWJOb3RDYW5jZWxsZWQAAAAAEQAAAAAAAAATU2Nob2xhcnNoaXBOb3RGb3VuZAAAAAAS",
         "AAAAAAAAADJQcmV2aWV3IGhvdyBtYW55IGFzc2V0cyBhIHNoYXJlIGFtb3VudCB3b3VsZCB5aWVsZAAAAAAAEHByZXZpZXdfd2l0aGRyYXcAAAABAAAAAAAAAAZzaGFyZXMAAAAAAAsAAAABAAAD6QAAAAsAAAfQAAAADVRyZWFzdXJ5RXJyb3IAAAA=",
         "AAAAAAAAAJtBcHByb3ZlIHNjaG9sYXJzaGlwIGZvciBhIG1lbWJlcgoKUmVsZWFzZXMgc2Nob2xhcnNoaXAgZnVuZHMgdG8gYSBtZW1iZXIncyBjbGFpbWFibGUgYmFsYW5jZS4KT25seSBjYWxsYWJsZSBieSBnb3Zlcm5vciAoZ292ZXJuYW5jZS9hZG1pbi9tZW50b3IgYXBwcm92YWwpLgAAAAATYXBwcm92ZV9zY2hvbGFyc2hpcAAAAAACAAAAAAAAAAZsYWJfaWQAAAAAAAQAAAAAAAAABm1lbWJlcgAAAAAAEwAAAAEAAAPpAAAD7QAAAAAAAAfQAAAADVRyZWFzdXJ5RXJyb3IAAAA=",
         "AAAAAAAAAI5XaXRoZHJhdyBzY2hvbGFyc2hpcCBmdW5kcwoKQWxsb3dzIG1lbWJlcnMgdG8gd2l0aGRyYXcgdGhlaXIgYXBwcm92ZWQgc2Nob2xhcnNoaXAgZnVuZHMuClVwZGF0ZWQgdG8gY2hlY2sgY2xhaW1hYmxlIGJhbGFuY2UgaW5zdGVhZCBvZiBzaGFyZXMuAAAAAAAUd2l0aGRyYXdfc2Nob2xhcnNoaXAAAAACAAAAAAAAAAZtZW1iZXIAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAEAAAPpAAAD7QAAAAAAAAfQAAAADVRyZWFzdXJ5RXJyb3IAAAA=",
-        "AAAAAAAAAGVHZXQgY2xhaW1hYmxlIGJhbGFuY2UgZm9yIGEgbWVtYmVyCgpSZXR1cm5zIHRoZSBhbW91bnQgb2Ygc2Nob2xhcnNoaXAgZnVuZHMgYXZhaWxhYmxlIGZvciB3aXRoZHJhd2FsLgAAAAAAABVnZXRfY2xhaW1hYmxlX2JhbGFuY2UAAAAAAAABAAAAAAAAAAZtZW1iZXIAAAAAABMAAAABAAAACw==",
+        "AAAAAAAAAHlHZXQgY2xhaW1hYmxlIHNjaG9sYXJzaGlwIGJhbGFuY2UgYWNyb3NzIGFsbCBsYWJzLCBpbmNsdWRpbmcgYW55IGJhbGFuY2UKcG9vbGVkIGJlZm9yZSBzY2hvbGFyc2hpcHMgd2VyZSB0cmFja2VkIHBlciBsYWIuAAAAAAAAFWdldF9jbGFpbWFibGVfYmFsYW5jZQAAAAAAAAEAAAAAAAAABm1lbWJlcgAAAAAAEwAAAAEAAAPpAAAACwAAB9AAAAANVHJlYXN1cnlFcnJvcgAAAA==",
         "AAAAAQAAABJMYWIgRnVuZGluZyBTdHJ1Y3QAAAAAAAAAAAADTGFiAAAAAAUAAAAAAAAABmZ1bmRlcgAAAAAAEwAAAAAAAAACaWQAAAAAAAQAAAAAAAAAFnNjaG9sYXJzaGlwX3Blcl9tZW1iZXIAAAAAAAsAAAAAAAAABnN0YXR1cwAAAAAH0AAAAAlMYWJTdGF0dXMAAAAAAAAAAAAADHRvdGFsX2Ftb3VudAAAAAs=",
         "AAAAAgAAACZTdG9yYWdlIGtleXMgZm9yIHRoZSBUcmVhc3VyeSBjb250cmFjdAAAAAAAAAAAAAdEYXRhS2V5AAAAAAkAAAAAAAAAGlZhbG9jcmFjeSBjb250cmFjdCBhZGRyZXNzAAAAAAAJVmFsb2NyYWN5AAAAAAAAAAAAADFHb3Zlcm5vciBjb250cmFjdCBhZGRyZXNzIChhdXRob3JpemVkIGZvciBzcGVuZHMpAAAAAAAACEdvdmVybm9yAAAAAAAAABZVbmRlcmx5aW5nIGFzc2V0IHRva2VuAAAAAAAKQXNzZXRUb2tlbgAAAAAAAAAAABhUb3RhbCBzaGFyZXMgb3V0c3RhbmRpbmcAAAALVG90YWxTaGFyZXMAAAAAAQAAABZVc2VyIGFkZHJlc3MgLT4gc2hhcmVzAAAAAAAKVXNlclNoYXJlcwAAAAAAAQAAABMAAAAAAAAAD1JlZW50cmFuY3kgbG9jawAAAAAOUmVlbnRyYW5jeUxvY2sAAAAAAAAAAAAfTGFiIGNvdW50ZXIgKGZvciBJRCBnZW5lcmF0aW9uKQAAAAAKTGFiQ291bnRlcgAAAAAAAQAAAA1MYWIgSUQgLT4gTGFiAAAAAAAAA0xhYgAAAAABAAAABAAAAAEAAAA1VXNlciBhZGRyZXNzIC0+IENsYWltYWJsZSBiYWxhbmNlIChTY2hvbGFyc2hpcCBmdW5kcykAAAAAAAAQQ2xhaW1hYmxlQmFsYW5jZQAAAAEAAAAT",
         "AAAAAgAAAA9TdGF0dXMgb2YgYSBMYWIAAAAAAAAAAAlMYWJTdGF0dXMAAAAAAAADAAAAAAAAAAAAAAAGQWN0aXZlAAAAAAAAAAAAAAAAAAlDYW5jZWxsZWQAAAAAAAAAAAAAAAAAAAlDb21wbGV0ZWQAAAA=" ]),
@@ -33,122 +33,1076 @@ if (typeof window !== 'undefined') {
 
 
 
+export const ValocracyError = {
+  1: {message:"AlreadyInitialized"},
+  2: {message:"NotInitialized"},
+  3: {message:"NotAuthorized"},
+  4: {message:"NonExistentValor"},
+  5: {message:"NonExistentToken"},
+  6: {message:"NonExistentAccount"},
+  7: {message:"TokenSoulbound"},
+  8: {message:"AlreadyRegistered"},
+  9: {message:"InvalidSignature"},
+  10: {message:"NonceUsed"},
+  11: {message:"SignatureExpired"},
+  12: {message:"InvalidValorId"},
+  13: {message:"MintNotAuthorized"},
+  14: {message:"BadgeNotMintable"},
+  15: {message:"ReentrancyDetected"},
+  16: {message:"SelfDelegation"},
+  17: {message:"DelegationChain"},
+  18: {message:"NotDelegated"},
+  19: {message:"TooManyDelegators"},
+  20: {message:"InvalidDecayConfig"},
+  21: {message:"InvalidCategory"},
+  22: {message:"NonExistentCategory"},
+  23: {message:"RarityCapExceeded"},
+  24: {message:"SignerNotAuthorized"},
+  25: {message:"TooManySigners"},
+  26: {message:"InvalidBatch"},
+  27: {message:"InvalidAmount"}
+}
+
+
+export interface UserStats {
+  expiry: u64;
+  level: u64;
+  permanent_level: u64;
+  verified: boolean;
+}
+
+
+export interface Valor {
+  metadata: string;
+  rarity: u64;
+}
+
 
 /**
- * Valor type definition with rarity and metadata
+ * Snapshot of the Mana-relevant stats of an account, written on every change.
  */
-export interface Valor {
+export interface Checkpoint {
+  expiry: u64;
+  grants: Array<Grant>;
+  level: u64;
+  permanent_level: u64;
+  timestamp: u64;
+}
+
+
+/**
+ * Snapshot of an account's delegation state, written on every delegation change.
+ */
+export interface DelegationCheckpoint {
+  delegate: Option<string>;
+  delegators: Array<string>;
+  timestamp: u64;
+}
+
+
+/**
+ * A held badge with its own decay window.
+ */
+export interface Grant {
+  expiry: u64;
+  minted_at: u64;
+  /**
+ * Whether the badge's rarity was added to the permanent level
+ */
+permanent: boolean;
+  rarity: u64;
+  token_id: u64;
+  valor_id: u64;
+}
+
+
+/**
+ * Decaying Mana scheduled to expire within one expiry bucket.
+ */
+export interface ExpiryBucket {
   /**
- * Metadata string (e.g., description, URI)
+ * Sum of the decaying bonus of grants expiring in this bucket
  */
-metadata: string;
+extra: u128;
   /**
- * The rarity multiplier for this valor type
+ * Sum of bonus * expiry, used to derive the bucket's mean expiry
  */
-rarity: u64;
+weighted: u128;
 }
 
 
 /**
- * User statistics including level and expiration
+ * Total Mana supply around an aggregate change, for historical lookups.
  */
-export interface UserStats {
+export interface SupplyCheckpoint {
+  mana_after: u64;
+  mana_before: u64;
+  timestamp: u64;
+}
+
+/**
+ * Shape of the Mana decay between a badge mint and its expiry.
+ */
+export enum DecayCurve {
+  Linear = 0,
+  Step = 1,
+  Exponential = 2,
+  PlateauLinear = 3,
+}
+
+
+/**
+ * Decay parameters in effect from `timestamp`, written on every config change.
+ */
+export interface DecayConfigCheckpoint {
+  config: DecayConfig;
+  timestamp: u64;
+}
+
+
+/**
+ * Governance-settable Mana decay parameters.
+ */
+export interface DecayConfig {
+  curve: DecayCurve;
   /**
- * The expiration timestamp (Unix seconds)
+ * Mana every registered account keeps after decay
  */
-expiry: u64;
+floor: u64;
   /**
- * The accumulated governance level
+ * Exponential curve only: seconds for the bonus to halve
  */
-level: u64;
+half_life: u64;
   /**
- * The permanent portion of level that never decays (e.g., Founder badge)
+ * Plateau curve only: seconds of full bonus after a mint
  */
-permanent_level: u64;
+plateau: u64;
   /**
- * Whether the user has verified their identity (ADR-003)
+ * Seconds from a mint until its bonus has fully decayed
  */
-verified: boolean;
+vacancy_period: u64;
 }
 
-export const ValocracyError = {
+/**
+ * Who may mint badges of a category through `mint`.
+ */
+export type MintPolicy = {tag: "SelfRegister", values: void} | {tag: "Governor", values: void} | {tag: "HoldersOf", values: readonly [u32]} | {tag: "AnyMember", values: void} | {tag: "Never", values: void};
+
+
+/**
+ * A governance-defined range of valor IDs sharing a minting policy.
+ */
+export interface Category {
+  id: u32;
   /**
-   * Contract has already been initialized
-   */
-  1: {message:"AlreadyInitialized"},
+ * Last valor ID of the category (inclusive)
+ */
+max_valor_id: u64;
+  /**
+ * First valor ID of the category (inclusive)
+ */
+min_valor_id: u64;
+  mint_policy: MintPolicy;
+  name: string;
+  /**
+ * Highest rarity a valor of this category may have
+ */
+rarity_cap: u64;
+}
+
+
+/**
+ * Audit record of a token removed by governance or renounced by its holder.
+ */
+export interface Revocation {
+  owner: string;
+  reason: string;
+  /**
+ * True when the holder gave up the badge themselves
+ */
+renounced: boolean;
+  revoked_at: u64;
+  valor_id: u64;
+}
+
+
+/**
+ * A backend key allowed to sign registrations, mints and renewals.
+ */
+export interface Signer {
+  /**
+ * Valor IDs this key may sign for; empty allows all
+ */
+allowed_valor_ids: Array<u64>;
+  /**
+ * Timestamp after which the key is no longer accepted; 0 never expires
+ */
+expires_at: u64;
+  public_key: Buffer;
+}
+
+/**
+ * Operation authorized by a backend signature, with the fields it covers.
+ */
+export type SignedAction = {tag: "SelfRegister", values: readonly [string]} | {tag: "GuardianMint", values: readonly [string, u64]} | {tag: "Renew", values: readonly [string]};
+
+
+/**
+ * Message signed by the backend, encoded as XDR.
+ * Binds a signature to one deployment on one network and to a single operation.
+ */
+export interface SignedMessage {
+  action: SignedAction;
+  contract: string;
+  expiry: u64;
+  network_id: Buffer;
+  nonce: u64;
+  version: u32;
+}
+
+/**
+ * Storage keys for the contract
+ */
+export type DataKey = {tag: "Initialized", values: void} | {tag: "Governor", values: void} | {tag: "Treasury", values: void} | {tag: "TotalSupply", values: void} | {tag: "MemberValorId", values: void} | {tag: "TokenValorId", values: readonly [u64]} | {tag: "ValorData", values: readonly [u64]} | {tag: "UserStats", values: readonly [string]} | {tag: "TokenOwner", values: readonly [u64]} | {tag: "Signer", values: void} | {tag: "UsedNonce", values: readonly [string, u64]} | {tag: "Delegate", values: readonly [string]} | {tag: "Delegators", values: readonly [string]} | {tag: "CheckpointCount", values: readonly [string]} | {tag: "Checkpoint", values: readonly [string, u32]} | {tag: "DelegationCheckpointCount", values: readonly [string]} | {tag: "DelegationCheckpoint", values: readonly [string, u32]} | {tag: "LegacyExpiry", values: readonly [string]} | {tag: "TotalMembers", values: void} | {tag: "TotalLevel", values: void} | {tag: "TotalPermanentExtra", values: void} | {tag: "PermanentExtra", values: readonly [string]} | {tag: "ExpiryBucket", values: readonly [u64]} | {tag: "SupplyCheckpointCount", values: void} | {tag: "SupplyCheckpoint", values: readonly [u32]} | {tag: "DecayConfig", values: void} | {tag: "DecayConfigCheckpointCount", values: void} | {tag: "DecayConfigCheckpoint", values: readonly [u32]} | {tag: "MaxExpiry", values: void} | {tag: "Grants", values: readonly [string]} | {tag: "Category", values: readonly [u32]} | {tag: "CategoryIds", values: void} | {tag: "Revocation", values: readonly [u64]} | {tag: "Signers", values: void} | {tag: "PermanentValor", values: readonly [u64]};
+
+export interface Client {
   /**
-   * Contract has not been initialized
+   * Construct and simulate a initialize transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Initialize the Valocracy contract.
+   * Registers initial valor types and mints genesis badges.
    */
-  2: {message:"NotInitialized"},
+  initialize: ({genesis_members, governor, treasury, member_valor_id, valor_ids, valor_rarities, valor_metadatas, leadership_valor_id, signer}: {genesis_members: Array<string>, governor: string, treasury: string, member_valor_id: u64, valor_ids: Array<u64>, valor_rarities: Array<u64>, valor_metadatas: Array<string>, leadership_valor_id: u64, signer: Buffer}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
   /**
-   * Caller is not authorized (not a member or not the governor)
+   * Construct and simulate a set_valor transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Create or update a Valor type (Governor only).
    */
-  3: {message:"NotAuthorized"},
+  set_valor: ({valor_id, rarity, metadata}: {valor_id: u64, rarity: u64, metadata: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
   /**
-   * Valor ID does not exist
+   * Construct and simulate a set_valor_permanent transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Mark whether badges of a valor add their rarity to the holder's permanent level
+   * when minted (Governor only). Badges already minted are not affected.
    */
-  4: {message:"NonExistentValor"},
+  set_valor_permanent: ({valor_id, permanent}: {valor_id: u64, permanent: boolean}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
   /**
-   * Token ID does not exist
+   * Construct and simulate a set_category transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Create or replace a badge category (Governor only).
+   * Ranges may not overlap those of other categories.
    */
-  5: {message:"NonExistentToken"},
+  set_category: ({category}: {category: Category}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
   /**
-   * Account does not exist
+   * Construct and simulate a mint transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Mint a new soulbound NFT. Requires authorization from a valid minter.
    */
-  6: {message:"NonExistentAccount"},
+  mint: ({minter, recipient, valor_id}: {minter: string, recipient: string, valor_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u64>>>
+
   /**
-   * Token is soulbound and cannot be transferred
+   * Construct and simulate a mint_batch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Mint one badge per (recipient, valor_id) pair with the same authorization as `mint`.
+   * Treasury shares are allocated in a single call. Fails as a whole if any mint fails.
    */
-  7: {message:"TokenSoulbound"},
+  mint_batch: ({minter, recipients, valor_ids}: {minter: string, recipients: Array<string>, valor_ids: Array<u64>}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Array<u64>>>>
+
   /**
-   * User has already self-registered (has a Member badge)
+   * Construct and simulate a self_register transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  8: {message:"AlreadyRegistered"},
+  self_register: ({caller, signer, signature, nonce, expiry}: {caller: string, signer: Buffer, signature: Buffer, nonce: u64, expiry: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u64>>>
+
   /**
-   * Invalid signature
+   * Construct and simulate a guardian_mint transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Mint a new soulbound NFT using backend signature.
    */
-  9: {message:"InvalidSignature"},
+  guardian_mint: ({account, valor_id, signer, signature, nonce, expiry}: {account: string, valor_id: u64, signer: Buffer, signature: Buffer, nonce: u64, expiry: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u64>>>
+
   /**
-   * Nonce already used
+   * Construct and simulate a renew transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Refresh the decay window of every badge held by `account` using backend signature.
+   * Records ongoing activity without minting a token. Returns the new expiry.
    */
-  10: {message:"NonceUsed"},
+  renew: ({account, signer, signature, nonce, expiry}: {account: string, signer: Buffer, signature: Buffer, nonce: u64, expiry: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u64>>>
+
+  /**
+   * Construct and simulate a revoke transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Revoke a badge token (Governor only).
+   * The reason is kept with the revocation record for auditing.
+   */
+  revoke: ({token_id, reason}: {token_id: u64, reason: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a renounce transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Give up a badge held by `owner`.
+   */
+  renounce: ({owner, token_id}: {owner: string, token_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a revocation_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the revocation record of a token that was revoked or renounced
+   */
+  revocation_of: ({token_id}: {token_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<Revocation>>>
+
+  /**
+   * Construct and simulate a grant_permanent transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Raise the permanent level of a member (Governor only).
+   * Mana never decays below the permanent level.
+   */
+  grant_permanent: ({account, amount, reason}: {account: string, amount: u64, reason: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u64>>>
+
+  /**
+   * Construct and simulate a reduce_permanent transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Lower the permanent level of a member (Governor only).
+   */
+  reduce_permanent: ({account, amount, reason}: {account: string, amount: u64, reason: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u64>>>
+
+  /**
+   * Construct and simulate a set_signer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Add a backend signer or replace the one with the same public key (Governor only).
+   */
+  set_signer: ({signer}: {signer: Signer}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a remove_signer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Remove a backend signer (Governor only).
+   */
+  remove_signer: ({public_key}: {public_key: Buffer}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a signing_payload transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the exact bytes a backend signer must sign to authorize `action`
+   */
+  signing_payload: ({action, nonce, expiry}: {action: SignedAction, nonce: u64, expiry: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Buffer>>
+
+  /**
+   * Construct and simulate a signers transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the backend signers
+   */
+  signers: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<Signer>>>
+
+  /**
+   * Construct and simulate a update_governor transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Update the governor contract address (Governor only).
+   */
+  update_governor: ({new_governor}: {new_governor: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a update_treasury transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Update the treasury contract address (Governor only).
+   */
+  update_treasury: ({new_treasury}: {new_treasury: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Upgrade the contract WASM hash (Governor only).
+   */
+  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a migrate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Backfill per-badge grants for badges minted before they were recorded (Governor only).
+   * Processes up to `limit` token IDs from `start_token_id` and returns the next ID to migrate.
+   * Mana is unchanged; migrated badges show up in `tokens_of` and the badge queries,
+   * and their owners are added to the total supply.
+   */
+  migrate: ({start_token_id, limit}: {start_token_id: u64, limit: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u64>>>
+
+  /**
+   * Construct and simulate a set_verified transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the verification status of a member (Governor only).
+   * Unverified members cannot withdraw funds from the treasury.
+   */
+  set_verified: ({member, verified}: {member: string, verified: boolean}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_decay_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Update the Mana decay parameters (Governor only).
+   * Existing badges keep their expiry; the new curve and floor apply from now on.
+   */
+  set_decay_config: ({config}: {config: DecayConfig}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a delegate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Delegate voting weight to another member.
+   * Badges stay soulbound; only the delegator's Mana is counted for the delegatee.
+   * Delegation is not transitive: delegatees cannot delegate and delegators cannot receive.
+   */
+  delegate: ({delegator, delegatee}: {delegator: string, delegatee: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a undelegate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Remove an existing delegation, restoring the delegator's own vote.
+   */
+  undelegate: ({delegator}: {delegator: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a delegates_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the account `account` currently delegates its voting weight to
+   */
+  delegates_of: ({account}: {account: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a delegators_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the accounts currently delegating to `account`
+   */
+  delegators_of: ({account}: {account: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<string>>>
+
+  /**
+   * Construct and simulate a delegates_at transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the account `account` delegated its voting weight to at `timestamp`
+   */
+  delegates_at: ({account, timestamp}: {account: string, timestamp: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a delegators_at transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the accounts delegating to `account` at `timestamp`
+   */
+  delegators_at: ({account, timestamp}: {account: string, timestamp: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<string>>>
+
+  /**
+   * Construct and simulate a name transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the contract name
+   */
+  name: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a symbol transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the contract symbol
+   */
+  symbol: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a total_supply transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get total supply of minted tokens
+   */
+  total_supply: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u64>>
+
+  /**
+   * Construct and simulate a vacancy_period transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the vacancy period in seconds
+   */
+  vacancy_period: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u64>>
+
   /**
-   * Signature expired
+   * Construct and simulate a decay_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the Mana decay parameters
    */
-  11: {message:"SignatureExpired"},
+  decay_config: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<DecayConfig>>
+
   /**
-   * Invalid Valor ID
+   * Construct and simulate a decay_config_at transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the Mana decay parameters in effect at `timestamp`
    */
-  12: {message:"InvalidValorId"},
+  decay_config_at: ({timestamp}: {timestamp: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<DecayConfig>>
+
   /**
-   * Mint not authorized
+   * Construct and simulate a governor transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the governor contract address
    */
-  13: {message:"MintNotAuthorized"},
+  governor: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<string>>>
+
   /**
-   * Badge not mintable
+   * Construct and simulate a treasury transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the treasury contract address
    */
-  14: {message:"BadgeNotMintable"},
+  treasury: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<string>>>
+
   /**
-   * Reentrancy detected
+   * Construct and simulate a valor_id_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the valor ID linked to a token
    */
-  15: {message:"ReentrancyDetected"}
-}
+  valor_id_of: ({token_id}: {token_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
 
-/**
- * Storage keys for the contract
- */
-export type DataKey = {tag: "Admin", values: void} | {tag: "Initialized", values: void} | {tag: "Founder", values: void} | {tag: "Governor", values: void} | {tag: "Treasury", values: void} | {tag: "TotalSupply", values: void} | {tag: "MemberValorId", values: void} | {tag: "TokenValorId", values: readonly [u64]} | {tag: "ValorData", values: readonly [u64]} | {tag: "UserStats", values: readonly [string]} | {tag: "TokenOwner", values: readonly [u64]} | {tag: "Signer", values: void} | {tag: "UsedNonce", values: readonly [string, u64]};
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<u64>>>
 
-export interface Client {
   /**
-   * Construct and simulate a mint transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Mint a new soulbound NFT to an account.
-   * 
-   * Requires authorization from a valid minter for the specific badge category.
-   * - Governance/Leadership/Track: See RBAC matrix
-   * - Community: Any member
+   * Construct and simulate a rarity_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the rarity of a valor type
    */
-  mint: ({minter, recipient, valor_id}: {minter: string, recipient: string, valor_id: u64}, options?: {
+  rarity_of: ({valor_id}: {valor_id: u64}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -163,13 +1117,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<u64>>>
+  }) => Promise<AssembledTransaction<u64>>
 
   /**
-   * Construct and simulate a name transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the contract name
+   * Construct and simulate a metadata_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the metadata of a valor type
    */
-  name: (options?: {
+  metadata_of: ({valor_id}: {valor_id: u64}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -187,13 +1141,10 @@ export interface Client {
   }) => Promise<AssembledTransaction<string>>
 
   /**
-   * Construct and simulate a revoke transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Revoke (burn) a badge token.
-   * 
-   * Governor-only. Removes the token, reduces the user's level by
-   * the badge's rarity value. Used for governance-decided removal.
+   * Construct and simulate a owner_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the owner of a token
    */
-  revoke: ({token_id}: {token_id: u64}, options?: {
+  owner_of: ({token_id}: {token_id: u64}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -208,13 +1159,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<void>>>
+  }) => Promise<AssembledTransaction<Option<string>>>
 
   /**
-   * Construct and simulate a symbol transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the contract symbol
+   * Construct and simulate a tokens_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the token IDs held by an account, oldest first
    */
-  symbol: (options?: {
+  tokens_of: ({account}: {account: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -229,13 +1180,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<string>>
+  }) => Promise<AssembledTransaction<Array<u64>>>
 
   /**
-   * Construct and simulate a founder transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the founder address
+   * Construct and simulate a tokens_of_paginated transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get up to `limit` token IDs held by an account, starting at index `start`
    */
-  founder: (options?: {
+  tokens_of_paginated: ({account, start, limit}: {account: string, start: u32, limit: u32}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -250,14 +1201,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Option<string>>>
+  }) => Promise<AssembledTransaction<Array<u64>>>
 
   /**
-   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Upgrade the contract to a new WASM hash.
-   * Only callable by the governor (requires governance proposal).
+   * Construct and simulate a balance_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the number of badges held by an account
    */
-  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: {
+  balance_of: ({account}: {account: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -272,13 +1222,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<void>>>
+  }) => Promise<AssembledTransaction<u32>>
 
   /**
-   * Construct and simulate a governor transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the governor contract address
+   * Construct and simulate a has_badge transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check whether an account holds at least one badge of a valor type
    */
-  governor: (options?: {
+  has_badge: ({account, valor_id}: {account: string, valor_id: u64}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -293,13 +1243,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Option<string>>>
+  }) => Promise<AssembledTransaction<boolean>>
 
   /**
-   * Construct and simulate a level_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the raw level of an account (without decay)
+   * Construct and simulate a count_badges transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Count the badges of a category held by an account
    */
-  level_of: ({account}: {account: string}, options?: {
+  count_badges: ({account, category_id}: {account: string, category_id: u32}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -314,13 +1264,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<u64>>
+  }) => Promise<AssembledTransaction<u32>>
 
   /**
-   * Construct and simulate a owner_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the owner of a token
+   * Construct and simulate a category transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a badge category by ID
    */
-  owner_of: ({token_id}: {token_id: u64}, options?: {
+  category: ({category_id}: {category_id: u32}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -335,13 +1285,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Option<string>>>
+  }) => Promise<AssembledTransaction<Option<Category>>>
 
   /**
-   * Construct and simulate a treasury transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the treasury contract address
+   * Construct and simulate a categories transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get all badge categories
    */
-  treasury: (options?: {
+  categories: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -356,13 +1306,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Option<string>>>
+  }) => Promise<AssembledTransaction<Array<Category>>>
 
   /**
-   * Construct and simulate a expiry_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the expiry timestamp of an account
+   * Construct and simulate a category_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the category a valor ID belongs to
    */
-  expiry_of: ({account}: {account: string}, options?: {
+  category_of: ({valor_id}: {valor_id: u64}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -377,16 +1327,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<u64>>
+  }) => Promise<AssembledTransaction<Option<Category>>>
 
   /**
-   * Construct and simulate a get_votes transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the current voting power (Mana) of an account
-   * 
-   * Mana = MEMBER_FLOOR + bonus (decay applies to extra_level only)
-   * Registered accounts always retain at least MEMBER_FLOOR voting power.
+   * Construct and simulate a level_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the raw level of an account (without decay)
    */
-  get_votes: ({account}: {account: string}, options?: {
+  level_of: ({account}: {account: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -404,10 +1351,10 @@ export interface Client {
   }) => Promise<AssembledTransaction<u64>>
 
   /**
-   * Construct and simulate a rarity_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the rarity of a valor type
+   * Construct and simulate a is_permanent_valor transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check whether badges of a valor add to the holder's permanent level
    */
-  rarity_of: ({valor_id}: {valor_id: u64}, options?: {
+  is_permanent_valor: ({valor_id}: {valor_id: u64}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -422,15 +1369,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<u64>>
+  }) => Promise<AssembledTransaction<boolean>>
 
   /**
-   * Construct and simulate a set_valor transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Create or update a Valor type with rarity and metadata.
-   * 
-   * Governor-only. Badge type changes require a governance proposal.
+   * Construct and simulate a permanent_level_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the permanent level of an account
    */
-  set_valor: ({valor_id, rarity, metadata}: {valor_id: u64, rarity: u64, metadata: string}, options?: {
+  permanent_level_of: ({account}: {account: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -445,28 +1390,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<void>>>
+  }) => Promise<AssembledTransaction<u64>>
 
   /**
-   * Construct and simulate a initialize transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Initialize the Valocracy contract.
-   * 
-   * No admin: sets all configuration at once. Registers initial valor types,
-   * mints the Founder badge, and stores the member badge ID for self-registration.
-   * 
-   * # Arguments
-   * * `founder` - Address that receives the permanent Founder badge
-   * * `governor` - Governor contract address
-   * * `treasury` - Treasury contract address
-   * * `name` - Contract name
-   * * `symbol` - Contract symbol
-   * * `member_valor_id` - Valor ID used by self_register() (the Member badge)
-   * * `valor_ids` - List of valor IDs to register
-   * * `valor_rarities` - List of rarities (parallel to valor_ids)
-   * * `valor_metadatas` - List of metadata strings (parallel to valor_ids)
-   * * `founder_valor_id` - Which valor_id is the Founder badge
+   * Construct and simulate a grant_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the badge held as `token_id` with its mint time and expiry
    */
-  initialize: ({founder, governor, treasury, member_valor_id, valor_ids, valor_rarities, valor_metadatas, founder_valor_id, signer}: {founder: string, governor: string, treasury: string, member_valor_id: u64, valor_ids: Array<u64>, valor_rarities: Array<u64>, valor_metadatas: Array<string>, founder_valor_id: u64, signer: Buffer}, options?: {
+  grant_of: ({token_id}: {token_id: u64}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -481,15 +1411,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<void>>>
+  }) => Promise<AssembledTransaction<Option<Grant>>>
 
   /**
-   * Construct and simulate a is_verified transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Check if a member has completed identity verification (ADR-003).
-   * 
-   * Returns false if the account is not registered.
+   * Construct and simulate a expiry_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the latest badge expiry timestamp of an account
    */
-  is_verified: ({account}: {account: string}, options?: {
+  expiry_of: ({account}: {account: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -504,13 +1432,14 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<boolean>>
+  }) => Promise<AssembledTransaction<u64>>
 
   /**
-   * Construct and simulate a metadata_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the metadata of a valor type
+   * Construct and simulate a get_votes transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current voting power (Mana) of an account.
+   * Registered accounts retain at least the configured floor.
    */
-  metadata_of: ({valor_id}: {valor_id: u64}, options?: {
+  get_votes: ({account}: {account: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -525,13 +1454,17 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<string>>
+  }) => Promise<AssembledTransaction<u64>>
 
   /**
-   * Construct and simulate a valor_id_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the valor ID linked to a token
+   * Construct and simulate a get_votes_at transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get voting power (Mana) at a specific timestamp (KRN-02).
+   * 
+   * Uses the stats checkpoint and decay config in effect at `timestamp`, so
+   * badges minted or revoked and config changes made afterwards do not
+   * change the result.
    */
-  valor_id_of: ({token_id}: {token_id: u64}, options?: {
+  get_votes_at: ({account, timestamp}: {account: string, timestamp: u64}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -546,16 +1479,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Option<u64>>>
+  }) => Promise<AssembledTransaction<u64>>
 
   /**
-   * Construct and simulate a set_verified transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Set the verification status of a member (ADR-003).
-   * 
-   * Governor-only. Used after identity verification is complete.
-   * Unverified members cannot withdraw funds from the treasury.
+   * Construct and simulate a num_checkpoints transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the number of stats checkpoints recorded for an account
    */
-  set_verified: ({member, verified}: {member: string, verified: boolean}, options?: {
+  num_checkpoints: ({account}: {account: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -570,13 +1500,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<void>>>
+  }) => Promise<AssembledTransaction<u32>>
 
   /**
-   * Construct and simulate a total_supply transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get total supply of minted tokens
+   * Construct and simulate a checkpoint_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a stats checkpoint of an account by index
    */
-  total_supply: (options?: {
+  checkpoint_of: ({account, index}: {account: string, index: u32}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -591,15 +1521,14 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<u64>>
+  }) => Promise<AssembledTransaction<Option<Checkpoint>>>
 
   /**
-   * Construct and simulate a guardian_mint transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Mint a new soulbound NFT using backend signature (Guardian).
-   * 
-   * Payload: account | valor_id | nonce | expiry
+   * Construct and simulate a get_voting_power transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get current voting power including delegated Mana.
+   * Returns 0 for accounts that have delegated their own weight away.
    */
-  guardian_mint: ({account, valor_id, signature, nonce, expiry}: {account: string, valor_id: u64, signature: Buffer, nonce: u64, expiry: u64}, options?: {
+  get_voting_power: ({account}: {account: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -614,12 +1543,16 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<u64>>>
+  }) => Promise<AssembledTransaction<u64>>
 
   /**
-   * Construct and simulate a self_register transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_voting_power_at transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get voting power including delegated Mana at a specific timestamp.
+   * 
+   * Uses the delegation checkpoint in effect at `timestamp`, so delegations
+   * made afterwards do not move weight into or out of the snapshot.
    */
-  self_register: ({caller, signature, nonce, expiry}: {caller: string, signature: Buffer, nonce: u64, expiry: u64}, options?: {
+  get_voting_power_at: ({account, timestamp}: {account: string, timestamp: u64}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -634,21 +1567,15 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<u64>>>
+  }) => Promise<AssembledTransaction<u64>>
 
   /**
-   * Construct and simulate a calculate_mana transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Calculate Mana (voting power with Member Floor)
-   * 
-   * Formula: Mana = floor + bonus
-   * - floor = MEMBER_FLOOR (fixed constant, e.g. 5)
-   * - extra_level = level - floor
-   * - bonus = (extra_level * time_remaining) / VACANCY_PERIOD
+   * Construct and simulate a has_voting_power transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check if account has any active voting power
    * 
-   * Inactive members decay to exactly MEMBER_FLOOR regardless of their
-   * accumulated level. Legacy status offers zero protection against inactivity.
+   * Any registered account (level > 0) always has at least the floor voting power.
    */
-  calculate_mana: ({level, permanent_level, expiry, current_time}: {level: u64, permanent_level: u64, expiry: u64, current_time: u64}, options?: {
+  has_voting_power: ({account}: {account: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -663,13 +1590,15 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<u64>>
+  }) => Promise<AssembledTransaction<boolean>>
 
   /**
-   * Construct and simulate a vacancy_period transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the vacancy period (180 days in seconds)
+   * Construct and simulate a is_verified transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check if a member has completed identity verification (ADR-003).
+   * 
+   * Returns false if the account is not registered.
    */
-  vacancy_period: (options?: {
+  is_verified: ({account}: {account: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -684,14 +1613,14 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<u64>>
+  }) => Promise<AssembledTransaction<boolean>>
 
   /**
-   * Construct and simulate a update_governor transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Update the governor contract address (migration path).
-   * Only callable by the current governor.
+   * Construct and simulate a total_mana transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current total Mana of all registered accounts (KRN-03).
+   * Used for participation threshold.
    */
-  update_governor: ({new_governor}: {new_governor: string}, options?: {
+  total_mana: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -706,14 +1635,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<void>>>
+  }) => Promise<AssembledTransaction<u64>>
 
   /**
-   * Construct and simulate a update_treasury transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Update the treasury contract address.
-   * Only callable by the current governor.
+   * Construct and simulate a total_mana_at transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the total Mana of all registered accounts at a specific timestamp.
    */
-  update_treasury: ({new_treasury}: {new_treasury: string}, options?: {
+  total_mana_at: ({timestamp}: {timestamp: u64}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -728,15 +1656,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<void>>>
+  }) => Promise<AssembledTransaction<u64>>
 
   /**
-   * Construct and simulate a has_voting_power transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Check if account has any active voting power
-   * 
-   * Any registered account (level > 0) always has at least MEMBER_FLOOR voting power.
+   * Construct and simulate a total_members transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the number of accounts with a level above zero
    */
-  has_voting_power: ({account}: {account: string}, options?: {
+  total_members: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -751,13 +1677,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<boolean>>
+  }) => Promise<AssembledTransaction<u64>>
 
   /**
-   * Construct and simulate a permanent_level_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the permanent level of an account
+   * Construct and simulate a total_level transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the sum of all account levels (without decay)
    */
-  permanent_level_of: ({account}: {account: string}, options?: {
+  total_level: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -792,38 +1718,91 @@ export class Client extends ContractClient {
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAAAAALtNaW50IGEgbmV3IHNvdWxib3VuZCBORlQgdG8gYW4gYWNjb3VudC4KClJlcXVpcmVzIGF1dGhvcml6YXRpb24gZnJvbSBhIHZhbGlkIG1pbnRlciBmb3IgdGhlIHNwZWNpZmljIGJhZGdlIGNhdGVnb3J5LgotIEdvdmVybmFuY2UvTGVhZGVyc2hpcC9UcmFjazogU2VlIFJCQUMgbWF0cml4Ci0gQ29tbXVuaXR5OiBBbnkgbWVtYmVyAAAAAARtaW50AAAAAwAAAAAAAAAGbWludGVyAAAAAAATAAAAAAAAAAlyZWNpcGllbnQAAAAAAAATAAAAAAAAAAh2YWxvcl9pZAAAAAYAAAABAAAD6QAAAAYAAAfQAAAADlZhbG9jcmFjeUVycm9yAAA=",
+      new ContractSpec([ "AAAAAAAAAFpJbml0aWFsaXplIHRoZSBWYWxvY3JhY3kgY29udHJhY3QuClJlZ2lzdGVycyBpbml0aWFsIHZhbG9yIHR5cGVzIGFuZCBtaW50cyBnZW5lc2lzIGJhZGdlcy4AAAAAAAppbml0aWFsaXplAAAAAAAJAAAAAAAAAA9nZW5lc2lzX21lbWJlcnMAAAAD6gAAABMAAAAAAAAACGdvdmVybm9yAAAAEwAAAAAAAAAIdHJlYXN1cnkAAAATAAAAAAAAAA9tZW1iZXJfdmFsb3JfaWQAAAAABgAAAAAAAAAJdmFsb3JfaWRzAAAAAAAD6gAAAAYAAAAAAAAADnZhbG9yX3Jhcml0aWVzAAAAAAPqAAAABgAAAAAAAAAPdmFsb3JfbWV0YWRhdGFzAAAAA+oAAAAQAAAAAAAAABNsZWFkZXJzaGlwX3ZhbG9yX2lkAAAAAAYAAAAAAAAABnNpZ25lcgAAAAAD7gAAACAAAAABAAAD6QAAAAIAAAfQAAAADlZhbG9jcmFjeUVycm9yAAA=",
+        "AAAAAAAAAC5DcmVhdGUgb3IgdXBkYXRlIGEgVmFsb3IgdHlwZSAoR292ZXJub3Igb25seSkuAAAAAAAJc2V0X3ZhbG9yAAAAAAAAAwAAAAAAAAAIdmFsb3JfaWQAAAAGAAAAAAAAAAZyYXJpdHkAAAAAAAYAAAAAAAAACG1ldGFkYXRhAAAAEAAAAAEAAAPpAAAAAgAAB9AAAAAOVmFsb2NyYWN5RXJyb3IAAA==",
+        "AAAAAAAAAJRNYXJrIHdoZXRoZXIgYmFkZ2VzIG9mIGEgdmFsb3IgYWRkIHRoZWlyIHJhcml0eSB0byB0aGUgaG9sZGVyJ3MgcGVybWFuZW50IGxldmVsCndoZW4gbWludGVkIChHb3Zlcm5vciBvbmx5KS4gQmFkZ2VzIGFscmVhZHkgbWludGVkIGFyZSBub3QgYWZmZWN0ZWQuAAAAE3NldF92YWxvcl9wZXJtYW5lbnQAAAAAAgAAAAAAAAAIdmFsb3JfaWQAAAAGAAAAAAAAAAlwZXJtYW5lbnQAAAAAAAABAAAAAQAAA+kAAAACAAAH0AAAAA5WYWxvY3JhY3lFcnJvcgAA",
+        "AAAAAAAAAGVDcmVhdGUgb3IgcmVwbGFjZSBhIGJhZGdlIGNhdGVnb3J5IChHb3Zlcm5vciBvbmx5KS4KUmFuZ2VzIG1heSBub3Qgb3ZlcmxhcCB0aG9zZSBvZiBvdGhlciBjYXRlZ29yaWVzLgAAAAAAAAxzZXRfY2F0ZWdvcnkAAAABAAAAAAAAAAhjYXRlZ29yeQAAB9AAAAAIQ2F0ZWdvcnkAAAABAAAD6QAAAAIAAAfQAAAADlZhbG9jcmFjeUVycm9yAAA=",
+        "AAAAAAAAAEVNaW50IGEgbmV3IHNvdWxib3VuZCBORlQuIFJlcXVpcmVzIGF1dGhvcml6YXRpb24gZnJvbSBhIHZhbGlkIG1pbnRlci4AAAAAAAAEbWludAAAAAMAAAAAAAAABm1pbnRlcgAAAAAAEwAAAAAAAAAJcmVjaXBpZW50AAAAAAAAEwAAAAAAAAAIdmFsb3JfaWQAAAAGAAAAAQAAA+kAAAAGAAAH0AAAAA5WYWxvY3JhY3lFcnJvcgAA",
+        "AAAAAAAAAKhNaW50IG9uZSBiYWRnZSBwZXIgKHJlY2lwaWVudCwgdmFsb3JfaWQpIHBhaXIgd2l0aCB0aGUgc2FtZSBhdXRob3JpemF0aW9uIGFzIGBtaW50YC4KVHJlYXN1cnkgc2hhcmVzIGFyZSBhbGxvY2F0ZWQgaW4gYSBzaW5nbGUgY2FsbC4gRmFpbHMgYXMgYSB3aG9sZSBpZiBhbnkgbWludCBmYWlscy4AAAAKbWludF9iYXRjaAAAAAAAAwAAAAAAAAAGbWludGVyAAAAAAATAAAAAAAAAApyZWNpcGllbnRzAAAAAAPqAAAAEwAAAAAAAAAJdmFsb3JfaWRzAAAAAAAD6gAAAAYAAAABAAAD6QAAA+oAAAAGAAAH0AAAAA5WYWxvY3JhY3lFcnJvcgAA",
+        "AAAAAAAAAAAAAAANc2VsZl9yZWdpc3RlcgAAAAAAAAUAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAAGc2lnbmVyAAAAAAPuAAAAIAAAAAAAAAAJc2lnbmF0dXJlAAAAAAAD7gAAAEAAAAAAAAAABW5vbmNlAAAAAAAABgAAAAAAAAAGZXhwaXJ5AAAAAAAGAAAAAQAAA+kAAAAGAAAH0AAAAA5WYWxvY3JhY3lFcnJvcgAA",
+        "AAAAAAAAADFNaW50IGEgbmV3IHNvdWxib3VuZCBORlQgdXNpbmcgYmFja2VuZCBzaWduYXR1cmUuAAAAAAAADWd1YXJkaWFuX21pbnQAAAAAAAAGAAAAAAAAAAdhY2NvdW50AAAAABMAAAAAAAAACHZhbG9yX2lkAAAABgAAAAAAAAAGc2lnbmVyAAAAAAPuAAAAIAAAAAAAAAAJc2lnbmF0dXJlAAAAAAAD7gAAAEAAAAAAAAAABW5vbmNlAAAAAAAABgAAAAAAAAAGZXhwaXJ5AAAAAAAGAAAAAQAAA+kAAAAGAAAH0AAAAA5WYWxvY3JhY3lFcnJvcgAA",
+        "AAAAAAAAAJxSZWZyZXNoIHRoZSBkZWNheSB3aW5kb3cgb2YgZXZlcnkgYmFkZ2UgaGVsZCBieSBgYWNjb3VudGAgdXNpbmcgYmFja2VuZCBzaWduYXR1cmUuClJlY29yZHMgb25nb2luZyBhY3Rpdml0eSB3aXRob3V0IG1pbnRpbmcgYSB0b2tlbi4gUmV0dXJucyB0aGUgbmV3IGV4cGlyeS4AAAAFcmVuZXcAAAAAAAAFAAAAAAAAAAdhY2NvdW50AAAAABMAAAAAAAAABnNpZ25lcgAAAAAD7gAAACAAAAAAAAAACXNpZ25hdHVyZQAAAAAAA+4AAABAAAAAAAAAAAVub25jZQAAAAAAAAYAAAAAAAAABmV4cGlyeQAAAAAABgAAAAEAAAPpAAAABgAAB9AAAAAOVmFsb2NyYWN5RXJyb3IAAA==",
+        "AAAAAAAAAGFSZXZva2UgYSBiYWRnZSB0b2tlbiAoR292ZXJub3Igb25seSkuClRoZSByZWFzb24gaXMga2VwdCB3aXRoIHRoZSByZXZvY2F0aW9uIHJlY29yZCBmb3IgYXVkaXRpbmcuAAAAAAAABnJldm9rZQAAAAAAAgAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAAAAAAZyZWFzb24AAAAAABAAAAABAAAD6QAAAAIAAAfQAAAADlZhbG9jcmFjeUVycm9yAAA=",
+        "AAAAAAAAACBHaXZlIHVwIGEgYmFkZ2UgaGVsZCBieSBgb3duZXJgLgAAAAhyZW5vdW5jZQAAAAIAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+kAAAACAAAH0AAAAA5WYWxvY3JhY3lFcnJvcgAA",
+        "AAAAAAAAAEJHZXQgdGhlIHJldm9jYXRpb24gcmVjb3JkIG9mIGEgdG9rZW4gdGhhdCB3YXMgcmV2b2tlZCBvciByZW5vdW5jZWQAAAAAAA1yZXZvY2F0aW9uX29mAAAAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+gAAAfQAAAAClJldm9jYXRpb24AAA==",
+        "AAAAAAAAAGNSYWlzZSB0aGUgcGVybWFuZW50IGxldmVsIG9mIGEgbWVtYmVyIChHb3Zlcm5vciBvbmx5KS4KTWFuYSBuZXZlciBkZWNheXMgYmVsb3cgdGhlIHBlcm1hbmVudCBsZXZlbC4AAAAAD2dyYW50X3Blcm1hbmVudAAAAAADAAAAAAAAAAdhY2NvdW50AAAAABMAAAAAAAAABmFtb3VudAAAAAAABgAAAAAAAAAGcmVhc29uAAAAAAAQAAAAAQAAA+kAAAAGAAAH0AAAAA5WYWxvY3JhY3lFcnJvcgAA",
+        "AAAAAAAAADZMb3dlciB0aGUgcGVybWFuZW50IGxldmVsIG9mIGEgbWVtYmVyIChHb3Zlcm5vciBvbmx5KS4AAAAAABByZWR1Y2VfcGVybWFuZW50AAAAAwAAAAAAAAAHYWNjb3VudAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAYAAAAAAAAABnJlYXNvbgAAAAAAEAAAAAEAAAPpAAAABgAAB9AAAAAOVmFsb2NyYWN5RXJyb3IAAA==",
+        "AAAAAAAAAFFBZGQgYSBiYWNrZW5kIHNpZ25lciBvciByZXBsYWNlIHRoZSBvbmUgd2l0aCB0aGUgc2FtZSBwdWJsaWMga2V5IChHb3Zlcm5vciBvbmx5KS4AAAAAAAAKc2V0X3NpZ25lcgAAAAAAAQAAAAAAAAAGc2lnbmVyAAAAAAfQAAAABlNpZ25lcgAAAAAAAQAAA+kAAAACAAAH0AAAAA5WYWxvY3JhY3lFcnJvcgAA",
+        "AAAAAAAAAChSZW1vdmUgYSBiYWNrZW5kIHNpZ25lciAoR292ZXJub3Igb25seSkuAAAADXJlbW92ZV9zaWduZXIAAAAAAAABAAAAAAAAAApwdWJsaWNfa2V5AAAAAAPuAAAAIAAAAAEAAAPpAAAAAgAAB9AAAAAOVmFsb2NyYWN5RXJyb3IAAA==",
+        "AAAAAAAAAERHZXQgdGhlIGV4YWN0IGJ5dGVzIGEgYmFja2VuZCBzaWduZXIgbXVzdCBzaWduIHRvIGF1dGhvcml6ZSBgYWN0aW9uYAAAAA9zaWduaW5nX3BheWxvYWQAAAAAAwAAAAAAAAAGYWN0aW9uAAAAAAfQAAAADFNpZ25lZEFjdGlvbgAAAAAAAAAFbm9uY2UAAAAAAAAGAAAAAAAAAAZleHBpcnkAAAAAAAYAAAABAAAADg==",
+        "AAAAAAAAABdHZXQgdGhlIGJhY2tlbmQgc2lnbmVycwAAAAAHc2lnbmVycwAAAAAAAAAAAQAAA+oAAAfQAAAABlNpZ25lcgAA",
+        "AAAAAAAAADVVcGRhdGUgdGhlIGdvdmVybm9yIGNvbnRyYWN0IGFkZHJlc3MgKEdvdmVybm9yIG9ubHkpLgAAAAAAAA91cGRhdGVfZ292ZXJub3IAAAAAAQAAAAAAAAAMbmV3X2dvdmVybm9yAAAAEwAAAAEAAAPpAAAAAgAAB9AAAAAOVmFsb2NyYWN5RXJyb3IAAA==",
+        "AAAAAAAAADVVcGRhdGUgdGhlIHRyZWFzdXJ5IGNvbnRyYWN0IGFkZHJlc3MgKEdvdmVybm9yIG9ubHkpLgAAAAAAAA91cGRhdGVfdHJlYXN1cnkAAAAAAQAAAAAAAAAMbmV3X3RyZWFzdXJ5AAAAEwAAAAEAAAPpAAAAAgAAB9AAAAAOVmFsb2NyYWN5RXJyb3IAAA==",
+        "AAAAAAAAAC9VcGdyYWRlIHRoZSBjb250cmFjdCBXQVNNIGhhc2ggKEdvdmVybm9yIG9ubHkpLgAAAAAHdXBncmFkZQAAAAABAAAAAAAAAA1uZXdfd2FzbV9oYXNoAAAAAAAD7gAAACAAAAABAAAD6QAAAAIAAAfQAAAADlZhbG9jcmFjeUVycm9yAAA=",
+        "AAAAAAAAATNCYWNrZmlsbCBwZXItYmFkZ2UgZ3JhbnRzIGZvciBiYWRnZXMgbWludGVkIGJlZm9yZSB0aGV5IHdlcmUgcmVjb3JkZWQgKEdvdmVybm9yIG9ubHkpLgpQcm9jZXNzZXMgdXAgdG8gYGxpbWl0YCB0b2tlbiBJRHMgZnJvbSBgc3RhcnRfdG9rZW5faWRgIGFuZCByZXR1cm5zIHRoZSBuZXh0IElEIHRvIG1pZ3JhdGUuCk1hbmEgaXMgdW5jaGFuZ2VkOyBtaWdyYXRlZCBiYWRnZXMgc2hvdyB1cCBpbiBgdG9rZW5zX29mYCBhbmQgdGhlIGJhZGdlIHF1ZXJpZXMsCmFuZCB0aGVpciBvd25lcnMgYXJlIGFkZGVkIHRvIHRoZSB0b3RhbCBzdXBwbHkuAAAAAAdtaWdyYXRlAAAAAAIAAAAAAAAADnN0YXJ0X3Rva2VuX2lkAAAAAAAGAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAD6QAAAAYAAAfQAAAADlZhbG9jcmFjeUVycm9yAAA=",
+        "AAAAAAAAAHRTZXQgdGhlIHZlcmlmaWNhdGlvbiBzdGF0dXMgb2YgYSBtZW1iZXIgKEdvdmVybm9yIG9ubHkpLgpVbnZlcmlmaWVkIG1lbWJlcnMgY2Fubm90IHdpdGhkcmF3IGZ1bmRzIGZyb20gdGhlIHRyZWFzdXJ5LgAAAAxzZXRfdmVyaWZpZWQAAAACAAAAAAAAAAZtZW1iZXIAAAAAABMAAAAAAAAACHZlcmlmaWVkAAAAAQAAAAEAAAPpAAAAAgAAB9AAAAAOVmFsb2NyYWN5RXJyb3IAAA==",
+        "AAAAAAAAAH9VcGRhdGUgdGhlIE1hbmEgZGVjYXkgcGFyYW1ldGVycyAoR292ZXJub3Igb25seSkuCkV4aXN0aW5nIGJhZGdlcyBrZWVwIHRoZWlyIGV4cGlyeTsgdGhlIG5ldyBjdXJ2ZSBhbmQgZmxvb3IgYXBwbHkgZnJvbSBub3cgb24uAAAAABBzZXRfZGVjYXlfY29uZmlnAAAAAQAAAAAAAAAGY29uZmlnAAAAAAfQAAAAC0RlY2F5Q29uZmlnAAAAAAEAAAPpAAAAAgAAB9AAAAAOVmFsb2NyYWN5RXJyb3IAAA==",
+        "AAAAAAAAANBEZWxlZ2F0ZSB2b3Rpbmcgd2VpZ2h0IHRvIGFub3RoZXIgbWVtYmVyLgpCYWRnZXMgc3RheSBzb3VsYm91bmQ7IG9ubHkgdGhlIGRlbGVnYXRvcidzIE1hbmEgaXMgY291bnRlZCBmb3IgdGhlIGRlbGVnYXRlZS4KRGVsZWdhdGlvbiBpcyBub3QgdHJhbnNpdGl2ZTogZGVsZWdhdGVlcyBjYW5ub3QgZGVsZWdhdGUgYW5kIGRlbGVnYXRvcnMgY2Fubm90IHJlY2VpdmUuAAAACGRlbGVnYXRlAAAAAgAAAAAAAAAJZGVsZWdhdG9yAAAAAAAAEwAAAAAAAAAJZGVsZWdhdGVlAAAAAAAAEwAAAAEAAAPpAAAAAgAAB9AAAAAOVmFsb2NyYWN5RXJyb3IAAA==",
+        "AAAAAAAAAEJSZW1vdmUgYW4gZXhpc3RpbmcgZGVsZWdhdGlvbiwgcmVzdG9yaW5nIHRoZSBkZWxlZ2F0b3IncyBvd24gdm90ZS4AAAAAAAp1bmRlbGVnYXRlAAAAAAABAAAAAAAAAAlkZWxlZ2F0b3IAAAAAAAATAAAAAQAAA+kAAAACAAAH0AAAAA5WYWxvY3JhY3lFcnJvcgAA",
+        "AAAAAAAAAEJHZXQgdGhlIGFjY291bnQgYGFjY291bnRgIGN1cnJlbnRseSBkZWxlZ2F0ZXMgaXRzIHZvdGluZyB3ZWlnaHQgdG8AAAAAAAxkZWxlZ2F0ZXNfb2YAAAABAAAAAAAAAAdhY2NvdW50AAAAABMAAAABAAAD6AAAABM=",
+        "AAAAAAAAADJHZXQgdGhlIGFjY291bnRzIGN1cnJlbnRseSBkZWxlZ2F0aW5nIHRvIGBhY2NvdW50YAAAAAAADWRlbGVnYXRvcnNfb2YAAAAAAAABAAAAAAAAAAdhY2NvdW50AAAAABMAAAABAAAD6gAAABM=",
+        "AAAAAAAAAEdHZXQgdGhlIGFjY291bnQgYGFjY291bnRgIGRlbGVnYXRlZCBpdHMgdm90aW5nIHdlaWdodCB0byBhdCBgdGltZXN0YW1wYAAAAAAMZGVsZWdhdGVzX2F0AAAAAgAAAAAAAAAHYWNjb3VudAAAAAATAAAAAAAAAAl0aW1lc3RhbXAAAAAAAAAGAAAAAQAAA+gAAAAT",
+        "AAAAAAAAADdHZXQgdGhlIGFjY291bnRzIGRlbGVnYXRpbmcgdG8gYGFjY291bnRgIGF0IGB0aW1lc3RhbXBgAAAAAA1kZWxlZ2F0b3JzX2F0AAAAAAAAAgAAAAAAAAAHYWNjb3VudAAAAAATAAAAAAAAAAl0aW1lc3RhbXAAAAAAAAAGAAAAAQAAA+oAAAAT",
         "AAAAAAAAABVHZXQgdGhlIGNvbnRyYWN0IG5hbWUAAAAAAAAEbmFtZQAAAAAAAAABAAAAEA==",
-        "AAAAAAAAAJpSZXZva2UgKGJ1cm4pIGEgYmFkZ2UgdG9rZW4uCgpHb3Zlcm5vci1vbmx5LiBSZW1vdmVzIHRoZSB0b2tlbiwgcmVkdWNlcyB0aGUgdXNlcidzIGxldmVsIGJ5CnRoZSBiYWRnZSdzIHJhcml0eSB2YWx1ZS4gVXNlZCBmb3IgZ292ZXJuYW5jZS1kZWNpZGVkIHJlbW92YWwuAAAAAAAGcmV2b2tlAAAAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAD6QAAA+0AAAAAAAAH0AAAAA5WYWxvY3JhY3lFcnJvcgAA",
         "AAAAAAAAABdHZXQgdGhlIGNvbnRyYWN0IHN5bWJvbAAAAAAGc3ltYm9sAAAAAAAAAAAAAQAAABA=",
-        "AAAAAAAAABdHZXQgdGhlIGZvdW5kZXIgYWRkcmVzcwAAAAAHZm91bmRlcgAAAAAAAAAAAQAAA+gAAAAT",
-        "AAAAAAAAAGZVcGdyYWRlIHRoZSBjb250cmFjdCB0byBhIG5ldyBXQVNNIGhhc2guCk9ubHkgY2FsbGFibGUgYnkgdGhlIGdvdmVybm9yIChyZXF1aXJlcyBnb3Zlcm5hbmNlIHByb3Bvc2FsKS4AAAAAAAd1cGdyYWRlAAAAAAEAAAAAAAAADW5ld193YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAEAAAPpAAAD7QAAAAAAAAfQAAAADlZhbG9jcmFjeUVycm9yAAA=",
+        "AAAAAAAAACFHZXQgdG90YWwgc3VwcGx5IG9mIG1pbnRlZCB0b2tlbnMAAAAAAAAMdG90YWxfc3VwcGx5AAAAAAAAAAEAAAAG",
+        "AAAAAAAAACFHZXQgdGhlIHZhY2FuY3kgcGVyaW9kIGluIHNlY29uZHMAAAAAAAAOdmFjYW5jeV9wZXJpb2QAAAAAAAAAAAABAAAABg==",
+        "AAAAAAAAAB1HZXQgdGhlIE1hbmEgZGVjYXkgcGFyYW1ldGVycwAAAAAAAAxkZWNheV9jb25maWcAAAAAAAAAAQAAB9AAAAALRGVjYXlDb25maWcA",
+        "AAAAAAAAADZHZXQgdGhlIE1hbmEgZGVjYXkgcGFyYW1ldGVycyBpbiBlZmZlY3QgYXQgYHRpbWVzdGFtcGAAAAAAAA9kZWNheV9jb25maWdfYXQAAAAAAQAAAAAAAAAJdGltZXN0YW1wAAAAAAAABgAAAAEAAAfQAAAAC0RlY2F5Q29uZmlnAA==",
         "AAAAAAAAACFHZXQgdGhlIGdvdmVybm9yIGNvbnRyYWN0IGFkZHJlc3MAAAAAAAAIZ292ZXJub3IAAAAAAAAAAQAAA+gAAAAT",
-        "AAAAAAAAAC9HZXQgdGhlIHJhdyBsZXZlbCBvZiBhbiBhY2NvdW50ICh3aXRob3V0IGRlY2F5KQAAAAAIbGV2ZWxfb2YAAAABAAAAAAAAAAdhY2NvdW50AAAAABMAAAABAAAABg==",
-        "AAAAAAAAABhHZXQgdGhlIG93bmVyIG9mIGEgdG9rZW4AAAAIb3duZXJfb2YAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAD6AAAABM=",
         "AAAAAAAAACFHZXQgdGhlIHRyZWFzdXJ5IGNvbnRyYWN0IGFkZHJlc3MAAAAAAAAIdHJlYXN1cnkAAAAAAAAAAQAAA+gAAAAT",
-        "AAAAAAAAACZHZXQgdGhlIGV4cGlyeSB0aW1lc3RhbXAgb2YgYW4gYWNjb3VudAAAAAAACWV4cGlyeV9vZgAAAAAAAAEAAAAAAAAAB2FjY291bnQAAAAAEwAAAAEAAAAG",
-        "AAAAAAAAALhHZXQgdGhlIGN1cnJlbnQgdm90aW5nIHBvd2VyIChNYW5hKSBvZiBhbiBhY2NvdW50CgpNYW5hID0gTUVNQkVSX0ZMT09SICsgYm9udXMgKGRlY2F5IGFwcGxpZXMgdG8gZXh0cmFfbGV2ZWwgb25seSkKUmVnaXN0ZXJlZCBhY2NvdW50cyBhbHdheXMgcmV0YWluIGF0IGxlYXN0IE1FTUJFUl9GTE9PUiB2b3RpbmcgcG93ZXIuAAAACWdldF92b3RlcwAAAAAAAAEAAAAAAAAAB2FjY291bnQAAAAAEwAAAAEAAAAG",
+        "AAAAAAAAACJHZXQgdGhlIHZhbG9yIElEIGxpbmtlZCB0byBhIHRva2VuAAAAAAALdmFsb3JfaWRfb2YAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+gAAAAG",
         "AAAAAAAAAB5HZXQgdGhlIHJhcml0eSBvZiBhIHZhbG9yIHR5cGUAAAAAAAlyYXJpdHlfb2YAAAAAAAABAAAAAAAAAAh2YWxvcl9pZAAAAAYAAAABAAAABg==",
-        "AAAAAAAAAHlDcmVhdGUgb3IgdXBkYXRlIGEgVmFsb3IgdHlwZSB3aXRoIHJhcml0eSBhbmQgbWV0YWRhdGEuCgpHb3Zlcm5vci1vbmx5LiBCYWRnZSB0eXBlIGNoYW5nZXMgcmVxdWlyZSBhIGdvdmVybmFuY2UgcHJvcG9zYWwuAAAAAAAACXNldF92YWxvcgAAAAAAAAMAAAAAAAAACHZhbG9yX2lkAAAABgAAAAAAAAAGcmFyaXR5AAAAAAAGAAAAAAAAAAhtZXRhZGF0YQAAABAAAAABAAAD6QAAA+0AAAAAAAAH0AAAAA5WYWxvY3JhY3lFcnJvcgAA",
-        "AAAAAAAAAshJbml0aWFsaXplIHRoZSBWYWxvY3JhY3kgY29udHJhY3QuCgpObyBhZG1pbjogc2V0cyBhbGwgY29uZmlndXJhdGlvbiBhdCBvbmNlLiBSZWdpc3RlcnMgaW5pdGlhbCB2YWxvciB0eXBlcywKbWludHMgdGhlIEZvdW5kZXIgYmFkZ2UsIGFuZCBzdG9yZXMgdGhlIG1lbWJlciBiYWRnZSBJRCBmb3Igc2VsZi1yZWdpc3RyYXRpb24uCgojIEFyZ3VtZW50cwoqIGBmb3VuZGVyYCAtIEFkZHJlc3MgdGhhdCByZWNlaXZlcyB0aGUgcGVybWFuZW50IEZvdW5kZXIgYmFkZ2UKKiBgZ292ZXJub3JgIC0gR292ZXJub3IgY29udHJhY3QgYWRkcmVzcwoqIGB0cmVhc3VyeWAgLSBUcmVhc3VyeSBjb250cmFjdCBhZGRyZXNzCiogYG5hbWVgIC0gQ29udHJhY3QgbmFtZQoqIGBzeW1ib2xgIC0gQ29udHJhY3Qgc3ltYm9sCiogYG1lbWJlcl92YWxvcl9pZGAgLSBWYWxvciBJRCB1c2VkIGJ5IHNlbGZfcmVnaXN0ZXIoKSAodGhlIE1lbWJlciBiYWRnZSkKKiBgdmFsb3JfaWRzYCAtIExpc3Qgb2YgdmFsb3IgSURzIHRvIHJlZ2lzdGVyCiogYHZhbG9yX3Jhcml0aWVzYCAtIExpc3Qgb2YgcmFyaXRpZXMgKHBhcmFsbGVsIHRvIHZhbG9yX2lkcykKKiBgdmFsb3JfbWV0YWRhdGFzYCAtIExpc3Qgb2YgbWV0YWRhdGEgc3RyaW5ncyAocGFyYWxsZWwgdG8gdmFsb3JfaWRzKQoqIGBmb3VuZGVyX3ZhbG9yX2lkYCAtIFdoaWNoIHZhbG9yX2lkIGlzIHRoZSBGb3VuZGVyIGJhZGdlAAAACmluaXRpYWxpemUAAAAAAAkAAAAAAAAAB2ZvdW5kZXIAAAAAEwAAAAAAAAAIZ292ZXJub3IAAAATAAAAAAAAAAh0cmVhc3VyeQAAABMAAAAAAAAAD21lbWJlcl92YWxvcl9pZAAAAAAGAAAAAAAAAAl2YWxvcl9pZHMAAAAAAAPqAAAABgAAAAAAAAAOdmFsb3JfcmFyaXRpZXMAAAAAA+oAAAAGAAAAAAAAAA92YWxvcl9tZXRhZGF0YXMAAAAD6gAAABAAAAAAAAAAEGZvdW5kZXJfdmFsb3JfaWQAAAAGAAAAAAAAAAZzaWduZXIAAAAAA+4AAAAgAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAAOVmFsb2NyYWN5RXJyb3IAAA==",
-        "AAAAAAAAAHFDaGVjayBpZiBhIG1lbWJlciBoYXMgY29tcGxldGVkIGlkZW50aXR5IHZlcmlmaWNhdGlvbiAoQURSLTAwMykuCgpSZXR1cm5zIGZhbHNlIGlmIHRoZSBhY2NvdW50IGlzIG5vdCByZWdpc3RlcmVkLgAAAAAAAAtpc192ZXJpZmllZAAAAAABAAAAAAAAAAdhY2NvdW50AAAAABMAAAABAAAAAQ==",
         "AAAAAAAAACBHZXQgdGhlIG1ldGFkYXRhIG9mIGEgdmFsb3IgdHlwZQAAAAttZXRhZGF0YV9vZgAAAAABAAAAAAAAAAh2YWxvcl9pZAAAAAYAAAABAAAAEA==",
-        "AAAAAAAAACJHZXQgdGhlIHZhbG9yIElEIGxpbmtlZCB0byBhIHRva2VuAAAAAAALdmFsb3JfaWRfb2YAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+gAAAAG",
-        "AAAAAAAAAKxTZXQgdGhlIHZlcmlmaWNhdGlvbiBzdGF0dXMgb2YgYSBtZW1iZXIgKEFEUi0wMDMpLgoKR292ZXJub3Itb25seS4gVXNlZCBhZnRlciBpZGVudGl0eSB2ZXJpZmljYXRpb24gaXMgY29tcGxldGUuClVudmVyaWZpZWQgbWVtYmVycyBjYW5ub3Qgd2l0aGRyYXcgZnVuZHMgZnJvbSB0aGUgdHJlYXN1cnkuAAAADHNldF92ZXJpZmllZAAAAAIAAAAAAAAABm1lbWJlcgAAAAAAEwAAAAAAAAAIdmVyaWZpZWQAAAABAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAAOVmFsb2NyYWN5RXJyb3IAAA==",
-        "AAAAAAAAACFHZXQgdG90YWwgc3VwcGx5IG9mIG1pbnRlZCB0b2tlbnMAAAAAAAAMdG90YWxfc3VwcGx5AAAAAAAAAAEAAAAG",
-        "AAAAAAAAAGpNaW50IGEgbmV3IHNvdWxib3VuZCBORlQgdXNpbmcgYmFja2VuZCBzaWduYXR1cmUgKEd1YXJkaWFuKS4KClBheWxvYWQ6IGFjY291bnQgfCB2YWxvcl9pZCB8IG5vbmNlIHwgZXhwaXJ5AAAAAAANZ3VhcmRpYW5fbWludAAAAAAAAAUAAAAAAAAAB2FjY291bnQAAAAAEwAAAAAAAAAIdmFsb3JfaWQAAAAGAAAAAAAAAAlzaWduYXR1cmUAAAAAAAPuAAAAQAAAAAAAAAAFbm9uY2UAAAAAAAAGAAAAAAAAAAZleHBpcnkAAAAAAAYAAAABAAAD6QAAAAYAAAfQAAAADlZhbG9jcmFjeUVycm9yAAA=",
-        "AAAAAAAAAAAAAAANc2VsZl9yZWdpc3RlcgAAAAAAAAQAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAAJc2lnbmF0dXJlAAAAAAAD7gAAAEAAAAAAAAAABW5vbmNlAAAAAAAABgAAAAAAAAAGZXhwaXJ5AAAAAAAGAAAAAQAAA+kAAAAGAAAH0AAAAA5WYWxvY3JhY3lFcnJvcgAA",
-        "AAAAAAAAAWZDYWxjdWxhdGUgTWFuYSAodm90aW5nIHBvd2VyIHdpdGggTWVtYmVyIEZsb29yKQoKRm9ybXVsYTogTWFuYSA9IGZsb29yICsgYm9udXMKLSBmbG9vciA9IE1FTUJFUl9GTE9PUiAoZml4ZWQgY29uc3RhbnQsIGUuZy4gNSkKLSBleHRyYV9sZXZlbCA9IGxldmVsIC0gZmxvb3IKLSBib251cyA9IChleHRyYV9sZXZlbCAqIHRpbWVfcmVtYWluaW5nKSAvIFZBQ0FOQ1lfUEVSSU9ECgpJbmFjdGl2ZSBtZW1iZXJzIGRlY2F5IHRvIGV4YWN0bHkgTUVNQkVSX0ZMT09SIHJlZ2FyZGxlc3Mgb2YgdGhlaXIKYWNjdW11bGF0ZWQgbGV2ZWwuIExlZ2FjeSBzdGF0dXMgb2ZmZXJzIHplcm8gcHJvdGVjdGlvbiBhZ2FpbnN0IGluYWN0aXZpdHkuAAAAAAAOY2FsY3VsYXRlX21hbmEAAAAAAAQAAAAAAAAABWxldmVsAAAAAAAABgAAAAAAAAAPcGVybWFuZW50X2xldmVsAAAAAAYAAAAAAAAABmV4cGlyeQAAAAAABgAAAAAAAAAMY3VycmVudF90aW1lAAAABgAAAAEAAAAG",
-        "AAAAAAAAACxHZXQgdGhlIHZhY2FuY3kgcGVyaW9kICgxODAgZGF5cyBpbiBzZWNvbmRzKQAAAA52YWNhbmN5X3BlcmlvZAAAAAAAAAAAAAEAAAAG",
-        "AAAAAAAAAF1VcGRhdGUgdGhlIGdvdmVybm9yIGNvbnRyYWN0IGFkZHJlc3MgKG1pZ3JhdGlvbiBwYXRoKS4KT25seSBjYWxsYWJsZSBieSB0aGUgY3VycmVudCBnb3Zlcm5vci4AAAAAAAAPdXBkYXRlX2dvdmVybm9yAAAAAAEAAAAAAAAADG5ld19nb3Zlcm5vcgAAABMAAAABAAAD6QAAA+0AAAAAAAAH0AAAAA5WYWxvY3JhY3lFcnJvcgAA",
-        "AAAAAAAAAExVcGRhdGUgdGhlIHRyZWFzdXJ5IGNvbnRyYWN0IGFkZHJlc3MuCk9ubHkgY2FsbGFibGUgYnkgdGhlIGN1cnJlbnQgZ292ZXJub3IuAAAAD3VwZGF0ZV90cmVhc3VyeQAAAAABAAAAAAAAAAxuZXdfdHJlYXN1cnkAAAATAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAAOVmFsb2NyYWN5RXJyb3IAAA==",
-        "AAAAAAAAAH9DaGVjayBpZiBhY2NvdW50IGhhcyBhbnkgYWN0aXZlIHZvdGluZyBwb3dlcgoKQW55IHJlZ2lzdGVyZWQgYWNjb3VudCAobGV2ZWwgPiAwKSBhbHdheXMgaGFzIGF0IGxlYXN0IE1FTUJFUl9GTE9PUiB2b3RpbmcgcG93ZXIuAAAAABBoYXNfdm90aW5nX3Bvd2VyAAAAAQAAAAAAAAAHYWNjb3VudAAAAAATAAAAAQAAAAE=",
+        "AAAAAAAAABhHZXQgdGhlIG93bmVyIG9mIGEgdG9rZW4AAAAIb3duZXJfb2YAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAD6AAAABM=",
+        "AAAAAAAAADJHZXQgdGhlIHRva2VuIElEcyBoZWxkIGJ5IGFuIGFjY291bnQsIG9sZGVzdCBmaXJzdAAAAAAACXRva2Vuc19vZgAAAAAAAAEAAAAAAAAAB2FjY291bnQAAAAAEwAAAAEAAAPqAAAABg==",
+        "AAAAAAAAAElHZXQgdXAgdG8gYGxpbWl0YCB0b2tlbiBJRHMgaGVsZCBieSBhbiBhY2NvdW50LCBzdGFydGluZyBhdCBpbmRleCBgc3RhcnRgAAAAAAAAE3Rva2Vuc19vZl9wYWdpbmF0ZWQAAAAAAwAAAAAAAAAHYWNjb3VudAAAAAATAAAAAAAAAAVzdGFydAAAAAAAAAQAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAPqAAAABg==",
+        "AAAAAAAAACtHZXQgdGhlIG51bWJlciBvZiBiYWRnZXMgaGVsZCBieSBhbiBhY2NvdW50AAAAAApiYWxhbmNlX29mAAAAAAABAAAAAAAAAAdhY2NvdW50AAAAABMAAAABAAAABA==",
+        "AAAAAAAAAEFDaGVjayB3aGV0aGVyIGFuIGFjY291bnQgaG9sZHMgYXQgbGVhc3Qgb25lIGJhZGdlIG9mIGEgdmFsb3IgdHlwZQAAAAAAAAloYXNfYmFkZ2UAAAAAAAACAAAAAAAAAAdhY2NvdW50AAAAABMAAAAAAAAACHZhbG9yX2lkAAAABgAAAAEAAAAB",
+        "AAAAAAAAADFDb3VudCB0aGUgYmFkZ2VzIG9mIGEgY2F0ZWdvcnkgaGVsZCBieSBhbiBhY2NvdW50AAAAAAAADGNvdW50X2JhZGdlcwAAAAIAAAAAAAAAB2FjY291bnQAAAAAEwAAAAAAAAALY2F0ZWdvcnlfaWQAAAAABAAAAAEAAAAE",
+        "AAAAAAAAABpHZXQgYSBiYWRnZSBjYXRlZ29yeSBieSBJRAAAAAAACGNhdGVnb3J5AAAAAQAAAAAAAAALY2F0ZWdvcnlfaWQAAAAABAAAAAEAAAPoAAAH0AAAAAhDYXRlZ29yeQ==",
+        "AAAAAAAAABhHZXQgYWxsIGJhZGdlIGNhdGVnb3JpZXMAAAAKY2F0ZWdvcmllcwAAAAAAAAAAAAEAAAPqAAAH0AAAAAhDYXRlZ29yeQ==",
+        "AAAAAAAAACZHZXQgdGhlIGNhdGVnb3J5IGEgdmFsb3IgSUQgYmVsb25ncyB0bwAAAAAAC2NhdGVnb3J5X29mAAAAAAEAAAAAAAAACHZhbG9yX2lkAAAABgAAAAEAAAPoAAAH0AAAAAhDYXRlZ29yeQ==",
+        "AAAAAAAAAC9HZXQgdGhlIHJhdyBsZXZlbCBvZiBhbiBhY2NvdW50ICh3aXRob3V0IGRlY2F5KQAAAAAIbGV2ZWxfb2YAAAABAAAAAAAAAAdhY2NvdW50AAAAABMAAAABAAAABg==",
+        "AAAAAAAAAENDaGVjayB3aGV0aGVyIGJhZGdlcyBvZiBhIHZhbG9yIGFkZCB0byB0aGUgaG9sZGVyJ3MgcGVybWFuZW50IGxldmVsAAAAABJpc19wZXJtYW5lbnRfdmFsb3IAAAAAAAEAAAAAAAAACHZhbG9yX2lkAAAABgAAAAEAAAAB",
         "AAAAAAAAACVHZXQgdGhlIHBlcm1hbmVudCBsZXZlbCBvZiBhbiBhY2NvdW50AAAAAAAAEnBlcm1hbmVudF9sZXZlbF9vZgAAAAAAAQAAAAAAAAAHYWNjb3VudAAAAAATAAAAAQAAAAY=",
-        "AAAAAQAAAC5WYWxvciB0eXBlIGRlZmluaXRpb24gd2l0aCByYXJpdHkgYW5kIG1ldGFkYXRhAAAAAAAAAAAABVZhbG9yAAAAAAAAAgAAAChNZXRhZGF0YSBzdHJpbmcgKGUuZy4sIGRlc2NyaXB0aW9uLCBVUkkpAAAACG1ldGFkYXRhAAAAEAAAAClUaGUgcmFyaXR5IG11bHRpcGxpZXIgZm9yIHRoaXMgdmFsb3IgdHlwZQAAAAAAAAZyYXJpdHkAAAAAAAY=",
-        "AAAAAQAAAC5Vc2VyIHN0YXRpc3RpY3MgaW5jbHVkaW5nIGxldmVsIGFuZCBleHBpcmF0aW9uAAAAAAAAAAAACVVzZXJTdGF0cwAAAAAAAAQAAAAnVGhlIGV4cGlyYXRpb24gdGltZXN0YW1wIChVbml4IHNlY29uZHMpAAAAAAZleHBpcnkAAAAAAAYAAAAgVGhlIGFjY3VtdWxhdGVkIGdvdmVybmFuY2UgbGV2ZWwAAAAFbGV2ZWwAAAAAAAAGAAAARlRoZSBwZXJtYW5lbnQgcG9ydGlvbiBvZiBsZXZlbCB0aGF0IG5ldmVyIGRlY2F5cyAoZS5nLiwgRm91bmRlciBiYWRnZSkAAAAAAA9wZXJtYW5lbnRfbGV2ZWwAAAAABgAAADZXaGV0aGVyIHRoZSB1c2VyIGhhcyB2ZXJpZmllZCB0aGVpciBpZGVudGl0eSAoQURSLTAwMykAAAAAAAh2ZXJpZmllZAAAAAE=",
-        "AAAABAAAAAAAAAAAAAAADlZhbG9jcmFjeUVycm9yAAAAAAAPAAAAJUNvbnRyYWN0IGhhcyBhbHJlYWR5IGJlZW4gaW5pdGlhbGl6ZWQAAAAAAAASQWxyZWFkeUluaXRpYWxpemVkAAAAAAABAAAAIUNvbnRyYWN0IGhhcyBub3QgYmVlbiBpbml0aWFsaXplZAAAAAAAAA5Ob3RJbml0aWFsaXplZAAAAAAAAgAAADtDYWxsZXIgaXMgbm90IGF1dGhvcml6ZWQgKG5vdCBhIG1lbWJlciBvciBub3QgdGhlIGdvdmVybm9yKQAAAAANTm90QXV0aG9yaXplZAAAAAAAAAMAAAAXVmFsb3IgSUQgZG9lcyBub3QgZXhpc3QAAAAAEE5vbkV4aXN0ZW50VmFsb3IAAAAEAAAAF1Rva2VuIElEIGRvZXMgbm90IGV4aXN0AAAAABBOb25FeGlzdGVudFRva2VuAAAABQAAABZBY2NvdW50IGRvZXMgbm90IGV4aXN0AAAAAAASTm9uRXhpc3RlbnRBY2NvdW50AAAAAAAGAAAALFRva2VuIGlzIHNvdWxib3VuZCBhbmQgY2Fubm90IGJlIHRyYW5zZmVycmVkAAAADlRva2VuU291bGJvdW5kAAAAAAAHAAAANVVzZXIgaGFzIGFscmVhZHkgc2VsZi1yZWdpc3RlcmVkIChoYXMgYSBNZW1iZXIgYmFkZ2UpAAAAAAAAEUFscmVhZHlSZWdpc3RlcmVkAAAAAAAACAAAABFJbnZhbGlkIHNpZ25hdHVyZQAAAAAAABBJbnZhbGlkU2lnbmF0dXJlAAAACQAAABJOb25jZSBhbHJlYWR5IHVzZWQAAAAAAAlOb25jZVVzZWQAAAAAAAAKAAAAEVNpZ25hdHVyZSBleHBpcmVkAAAAAAAAEFNpZ25hdHVyZUV4cGlyZWQAAAALAAAAEEludmFsaWQgVmFsb3IgSUQAAAAOSW52YWxpZFZhbG9ySWQAAAAAAAwAAAATTWludCBub3QgYXV0aG9yaXplZAAAAAARTWludE5vdEF1dGhvcml6ZWQAAAAAAAANAAAAEkJhZGdlIG5vdCBtaW50YWJsZQAAAAAAEEJhZGdlTm90TWludGFibGUAAAAOAAAAE1JlZW50cmFuY3kgZGV0ZWN0ZWQAAAAAElJlZW50cmFuY3lEZXRlY3RlZAAAAAAADw==",
-        "AAAAAgAAAB1TdG9yYWdlIGtleXMgZm9yIHRoZSBjb250cmFjdAAAAAAAAAAAAAAHRGF0YUtleQAAAAANAAAAAAAAAEpBZG1pbiBhZGRyZXNzIChrZXB0IGZvciBiYWNrd2FyZCBjb21wYXQgZHVyaW5nIG1pZ3JhdGlvbiwgd2lsbCBiZSByZW1vdmVkKQAAAAAABUFkbWluAAAAAAAAAAAAAClXaGV0aGVyIHRoZSBjb250cmFjdCBoYXMgYmVlbiBpbml0aWFsaXplZAAAAAAAAAtJbml0aWFsaXplZAAAAAAAAAAAMkZvdW5kZXIgYWRkcmVzcyAocmVjZWl2ZXMgcGVybWFuZW50IEZvdW5kZXIgYmFkZ2UpAAAAAAAHRm91bmRlcgAAAAAAAAAAGUdvdmVybm9yIGNvbnRyYWN0IGFkZHJlc3MAAAAAAAAIR292ZXJub3IAAAAAAAAAGVRyZWFzdXJ5IGNvbnRyYWN0IGFkZHJlc3MAAAAAAAAIVHJlYXN1cnkAAAAAAAAAFlRvdGFsIHN1cHBseSBvZiB0b2tlbnMAAAAAAAtUb3RhbFN1cHBseQAAAAAAAAAAOVRoZSB2YWxvcl9pZCB1c2VkIGJ5IHNlbGZfcmVnaXN0ZXIoKSBmb3IgdGhlIE1lbWJlciBiYWRnZQAAAAAAAA1NZW1iZXJWYWxvcklkAAAAAAAAAQAAABxUb2tlbiBJRCAtPiBWYWxvciBJRCBtYXBwaW5nAAAADFRva2VuVmFsb3JJZAAAAAEAAAAGAAAAAQAAAB5WYWxvciBJRCAtPiBWYWxvciBkYXRhIG1hcHBpbmcAAAAAAAlWYWxvckRhdGEAAAAAAAABAAAABgAAAAEAAAAcQWNjb3VudCAtPiBVc2VyU3RhdHMgbWFwcGluZwAAAAlVc2VyU3RhdHMAAAAAAAABAAAAEwAAAAEAAAAhVG9rZW4gSUQgLT4gT3duZXIgYWRkcmVzcyBtYXBwaW5nAAAAAAAAClRva2VuT3duZXIAAAAAAAEAAAAGAAAAAAAAAC1CYWNrZW5kIHB1YmxpYyBrZXkgZm9yIHNpZ25hdHVyZSB2ZXJpZmljYXRpb24AAAAAAAAGU2lnbmVyAAAAAAABAAAAOVVzZWQgbm9uY2VzIGZvciByZXBsYXkgcHJvdGVjdGlvbjogKEFkZHJlc3MsIHU2NCkgLT4gYm9vbAAAAAAAAAlVc2VkTm9uY2UAAAAAAAACAAAAEwAAAAY=" ]),
+        "AAAAAAAAAD5HZXQgdGhlIGJhZGdlIGhlbGQgYXMgYHRva2VuX2lkYCB3aXRoIGl0cyBtaW50IHRpbWUgYW5kIGV4cGlyeQAAAAAACGdyYW50X29mAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+gAAAfQAAAABUdyYW50AAAA",
+        "AAAAAAAAADNHZXQgdGhlIGxhdGVzdCBiYWRnZSBleHBpcnkgdGltZXN0YW1wIG9mIGFuIGFjY291bnQAAAAACWV4cGlyeV9vZgAAAAAAAAEAAAAAAAAAB2FjY291bnQAAAAAEwAAAAEAAAAG",
+        "AAAAAAAAAGxHZXQgdGhlIGN1cnJlbnQgdm90aW5nIHBvd2VyIChNYW5hKSBvZiBhbiBhY2NvdW50LgpSZWdpc3RlcmVkIGFjY291bnRzIHJldGFpbiBhdCBsZWFzdCB0aGUgY29uZmlndXJlZCBmbG9vci4AAAAJZ2V0X3ZvdGVzAAAAAAAAAQAAAAAAAAAHYWNjb3VudAAAAAATAAAAAQAAAAY=",
+        "AAAAAAAAANhHZXQgdm90aW5nIHBvd2VyIChNYW5hKSBhdCBhIHNwZWNpZmljIHRpbWVzdGFtcCAoS1JOLTAyKS4KClVzZXMgdGhlIHN0YXRzIGNoZWNrcG9pbnQgYW5kIGRlY2F5IGNvbmZpZyBpbiBlZmZlY3QgYXQgYHRpbWVzdGFtcGAsIHNvCmJhZGdlcyBtaW50ZWQgb3IgcmV2b2tlZCBhbmQgY29uZmlnIGNoYW5nZXMgbWFkZSBhZnRlcndhcmRzIGRvIG5vdApjaGFuZ2UgdGhlIHJlc3VsdC4AAAAMZ2V0X3ZvdGVzX2F0AAAAAgAAAAAAAAAHYWNjb3VudAAAAAATAAAAAAAAAAl0aW1lc3RhbXAAAAAAAAAGAAAAAQAAAAY=",
+        "AAAAAAAAADtHZXQgdGhlIG51bWJlciBvZiBzdGF0cyBjaGVja3BvaW50cyByZWNvcmRlZCBmb3IgYW4gYWNjb3VudAAAAAAPbnVtX2NoZWNrcG9pbnRzAAAAAAEAAAAAAAAAB2FjY291bnQAAAAAEwAAAAEAAAAE",
+        "AAAAAAAAAC1HZXQgYSBzdGF0cyBjaGVja3BvaW50IG9mIGFuIGFjY291bnQgYnkgaW5kZXgAAAAAAAANY2hlY2twb2ludF9vZgAAAAAAAAIAAAAAAAAAB2FjY291bnQAAAAAEwAAAAAAAAAFaW5kZXgAAAAAAAAEAAAAAQAAA+gAAAfQAAAACkNoZWNrcG9pbnQAAA==",
+        "AAAAAAAAAHRHZXQgY3VycmVudCB2b3RpbmcgcG93ZXIgaW5jbHVkaW5nIGRlbGVnYXRlZCBNYW5hLgpSZXR1cm5zIDAgZm9yIGFjY291bnRzIHRoYXQgaGF2ZSBkZWxlZ2F0ZWQgdGhlaXIgb3duIHdlaWdodCBhd2F5LgAAABBnZXRfdm90aW5nX3Bvd2VyAAAAAQAAAAAAAAAHYWNjb3VudAAAAAATAAAAAQAAAAY=",
+        "AAAAAAAAAMtHZXQgdm90aW5nIHBvd2VyIGluY2x1ZGluZyBkZWxlZ2F0ZWQgTWFuYSBhdCBhIHNwZWNpZmljIHRpbWVzdGFtcC4KClVzZXMgdGhlIGRlbGVnYXRpb24gY2hlY2twb2ludCBpbiBlZmZlY3QgYXQgYHRpbWVzdGFtcGAsIHNvIGRlbGVnYXRpb25zCm1hZGUgYWZ0ZXJ3YXJkcyBkbyBub3QgbW92ZSB3ZWlnaHQgaW50byBvciBvdXQgb2YgdGhlIHNuYXBzaG90LgAAAAATZ2V0X3ZvdGluZ19wb3dlcl9hdAAAAAACAAAAAAAAAAdhY2NvdW50AAAAABMAAAAAAAAACXRpbWVzdGFtcAAAAAAAAAYAAAABAAAABg==",
+        "AAAAAAAAAHxDaGVjayBpZiBhY2NvdW50IGhhcyBhbnkgYWN0aXZlIHZvdGluZyBwb3dlcgoKQW55IHJlZ2lzdGVyZWQgYWNjb3VudCAobGV2ZWwgPiAwKSBhbHdheXMgaGFzIGF0IGxlYXN0IHRoZSBmbG9vciB2b3RpbmcgcG93ZXIuAAAAEGhhc192b3RpbmdfcG93ZXIAAAABAAAAAAAAAAdhY2NvdW50AAAAABMAAAABAAAAAQ==",
+        "AAAAAAAAAHFDaGVjayBpZiBhIG1lbWJlciBoYXMgY29tcGxldGVkIGlkZW50aXR5IHZlcmlmaWNhdGlvbiAoQURSLTAwMykuCgpSZXR1cm5zIGZhbHNlIGlmIHRoZSBhY2NvdW50IGlzIG5vdCByZWdpc3RlcmVkLgAAAAAAAAtpc192ZXJpZmllZAAAAAABAAAAAAAAAAdhY2NvdW50AAAAABMAAAABAAAAAQ==",
+        "AAAAAAAAAGFHZXQgdGhlIGN1cnJlbnQgdG90YWwgTWFuYSBvZiBhbGwgcmVnaXN0ZXJlZCBhY2NvdW50cyAoS1JOLTAzKS4KVXNlZCBmb3IgcGFydGljaXBhdGlvbiB0aHJlc2hvbGQuAAAAAAAACnRvdGFsX21hbmEAAAAAAAAAAAABAAAABg==",
+        "AAAAAAAAAEZHZXQgdGhlIHRvdGFsIE1hbmEgb2YgYWxsIHJlZ2lzdGVyZWQgYWNjb3VudHMgYXQgYSBzcGVjaWZpYyB0aW1lc3RhbXAuAAAAAAANdG90YWxfbWFuYV9hdAAAAAAAAAEAAAAAAAAACXRpbWVzdGFtcAAAAAAAAAYAAAABAAAABg==",
+        "AAAAAAAAADJHZXQgdGhlIG51bWJlciBvZiBhY2NvdW50cyB3aXRoIGEgbGV2ZWwgYWJvdmUgemVybwAAAAAADXRvdGFsX21lbWJlcnMAAAAAAAAAAAAAAQAAAAY=",
+        "AAAAAAAAADFHZXQgdGhlIHN1bSBvZiBhbGwgYWNjb3VudCBsZXZlbHMgKHdpdGhvdXQgZGVjYXkpAAAAAAAAC3RvdGFsX2xldmVsAAAAAAAAAAABAAAABg==",
+        "AAAABAAAAAAAAAAAAAAADlZhbG9jcmFjeUVycm9yAAAAAAAbAAAAAAAAABJBbHJlYWR5SW5pdGlhbGl6ZWQAAAAAAAEAAAAAAAAADk5vdEluaXRpYWxpemVkAAAAAAACAAAAAAAAAA1Ob3RBdXRob3JpemVkAAAAAAAAAwAAAAAAAAAQTm9uRXhpc3RlbnRWYWxvcgAAAAQAAAAAAAAAEE5vbkV4aXN0ZW50VG9rZW4AAAAFAAAAAAAAABJOb25FeGlzdGVudEFjY291bnQAAAAAAAYAAAAAAAAADlRva2VuU291bGJvdW5kAAAAAAAHAAAAAAAAABFBbHJlYWR5UmVnaXN0ZXJlZAAAAAAAAAgAAAAAAAAAEEludmFsaWRTaWduYXR1cmUAAAAJAAAAAAAAAAlOb25jZVVzZWQAAAAAAAAKAAAAAAAAABBTaWduYXR1cmVFeHBpcmVkAAAACwAAAAAAAAAOSW52YWxpZFZhbG9ySWQAAAAAAAwAAAAAAAAAEU1pbnROb3RBdXRob3JpemVkAAAAAAAADQAAAAAAAAAQQmFkZ2VOb3RNaW50YWJsZQAAAA4AAAAAAAAAElJlZW50cmFuY3lEZXRlY3RlZAAAAAAADwAAAAAAAAAOU2VsZkRlbGVnYXRpb24AAAAAABAAAAAAAAAAD0RlbGVnYXRpb25DaGFpbgAAAAARAAAAAAAAAAxOb3REZWxlZ2F0ZWQAAAASAAAAAAAAABFUb29NYW55RGVsZWdhdG9ycwAAAAAAABMAAAAAAAAAEkludmFsaWREZWNheUNvbmZpZwAAAAAAFAAAAAAAAAAPSW52YWxpZENhdGVnb3J5AAAAABUAAAAAAAAAE05vbkV4aXN0ZW50Q2F0ZWdvcnkAAAAAFgAAAAAAAAARUmFyaXR5Q2FwRXhjZWVkZWQAAAAAAAAXAAAAAAAAABNTaWduZXJOb3RBdXRob3JpemVkAAAAABgAAAAAAAAADlRvb01hbnlTaWduZXJzAAAAAAAZAAAAAAAAAAxJbnZhbGlkQmF0Y2gAAAAaAAAAAAAAAA1JbnZhbGlkQW1vdW50AAAAAAAAGw==",
+        "AAAAAQAAAAAAAAAAAAAACVVzZXJTdGF0cwAAAAAAAAQAAAAAAAAABmV4cGlyeQAAAAAABgAAAAAAAAAFbGV2ZWwAAAAAAAAGAAAAAAAAAA9wZXJtYW5lbnRfbGV2ZWwAAAAABgAAAAAAAAAIdmVyaWZpZWQAAAAB",
+        "AAAAAQAAAAAAAAAAAAAABVZhbG9yAAAAAAAAAgAAAAAAAAAIbWV0YWRhdGEAAAAQAAAAAAAAAAZyYXJpdHkAAAAAAAY=",
+        "AAAAAQAAAEtTbmFwc2hvdCBvZiB0aGUgTWFuYS1yZWxldmFudCBzdGF0cyBvZiBhbiBhY2NvdW50LCB3cml0dGVuIG9uIGV2ZXJ5IGNoYW5nZS4AAAAAAAAAAApDaGVja3BvaW50AAAAAAAFAAAAAAAAAAZleHBpcnkAAAAAAAYAAAAAAAAABmdyYW50cwAAAAAD6gAAB9AAAAAFR3JhbnQAAAAAAAAAAAAABWxldmVsAAAAAAAABgAAAAAAAAAPcGVybWFuZW50X2xldmVsAAAAAAYAAAAAAAAACXRpbWVzdGFtcAAAAAAAAAY=",
+        "AAAAAQAAAE5TbmFwc2hvdCBvZiBhbiBhY2NvdW50J3MgZGVsZWdhdGlvbiBzdGF0ZSwgd3JpdHRlbiBvbiBldmVyeSBkZWxlZ2F0aW9uIGNoYW5nZS4AAAAAAAAAAAAURGVsZWdhdGlvbkNoZWNrcG9pbnQAAAADAAAAAAAAAAhkZWxlZ2F0ZQAAA+gAAAATAAAAAAAAAApkZWxlZ2F0b3JzAAAAAAPqAAAAEwAAAAAAAAAJdGltZXN0YW1wAAAAAAAABg==",
+        "AAAAAQAAACdBIGhlbGQgYmFkZ2Ugd2l0aCBpdHMgb3duIGRlY2F5IHdpbmRvdy4AAAAAAAAAAAVHcmFudAAAAAAAAAYAAAAAAAAABmV4cGlyeQAAAAAABgAAAAAAAAAJbWludGVkX2F0AAAAAAAABgAAADtXaGV0aGVyIHRoZSBiYWRnZSdzIHJhcml0eSB3YXMgYWRkZWQgdG8gdGhlIHBlcm1hbmVudCBsZXZlbAAAAAAJcGVybWFuZW50AAAAAAAAAQAAAAAAAAAGcmFyaXR5AAAAAAAGAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAAAAAAACHZhbG9yX2lkAAAABg==",
+        "AAAAAQAAADtEZWNheWluZyBNYW5hIHNjaGVkdWxlZCB0byBleHBpcmUgd2l0aGluIG9uZSBleHBpcnkgYnVja2V0LgAAAAAAAAAADEV4cGlyeUJ1Y2tldAAAAAIAAAA7U3VtIG9mIHRoZSBkZWNheWluZyBib251cyBvZiBncmFudHMgZXhwaXJpbmcgaW4gdGhpcyBidWNrZXQAAAAABWV4dHJhAAAAAAAACgAAAD5TdW0gb2YgYm9udXMgKiBleHBpcnksIHVzZWQgdG8gZGVyaXZlIHRoZSBidWNrZXQncyBtZWFuIGV4cGlyeQAAAAAACHdlaWdodGVkAAAACg==",
+        "AAAAAQAAAEVUb3RhbCBNYW5hIHN1cHBseSBhcm91bmQgYW4gYWdncmVnYXRlIGNoYW5nZSwgZm9yIGhpc3RvcmljYWwgbG9va3Vwcy4AAAAAAAAAAAAAEFN1cHBseUNoZWNrcG9pbnQAAAADAAAAAAAAAAptYW5hX2FmdGVyAAAAAAAGAAAAAAAAAAttYW5hX2JlZm9yZQAAAAAGAAAAAAAAAAl0aW1lc3RhbXAAAAAAAAAG",
+        "AAAAAwAAADxTaGFwZSBvZiB0aGUgTWFuYSBkZWNheSBiZXR3ZWVuIGEgYmFkZ2UgbWludCBhbmQgaXRzIGV4cGlyeS4AAAAAAAAACkRlY2F5Q3VydmUAAAAAAAQAAAAqQm9udXMgZGVjcmVhc2VzIGxpbmVhcmx5IHRvIHplcm8gYXQgZXhwaXJ5AAAAAAAGTGluZWFyAAAAAAAAAAAAK0Z1bGwgYm9udXMgdW50aWwgZXhwaXJ5LCB0aGVuIGRyb3BzIHRvIHplcm8AAAAABFN0ZXAAAAABAAAAQkJvbnVzIGhhbHZlcyBldmVyeSBgaGFsZl9saWZlYCBzZWNvbmRzIGFuZCBkcm9wcyB0byB6ZXJvIGF0IGV4cGlyeQAAAAAAC0V4cG9uZW50aWFsAAAAAAIAAAA/RnVsbCBib251cyBmb3IgYHBsYXRlYXVgIHNlY29uZHMsIHRoZW4gbGluZWFyIHRvIHplcm8gYXQgZXhwaXJ5AAAAAA1QbGF0ZWF1TGluZWFyAAAAAAAAAw==",
+        "AAAAAQAAAExEZWNheSBwYXJhbWV0ZXJzIGluIGVmZmVjdCBmcm9tIGB0aW1lc3RhbXBgLCB3cml0dGVuIG9uIGV2ZXJ5IGNvbmZpZyBjaGFuZ2UuAAAAAAAAABVEZWNheUNvbmZpZ0NoZWNrcG9pbnQAAAAAAAACAAAAAAAAAAZjb25maWcAAAAAB9AAAAALRGVjYXlDb25maWcAAAAAAAAAAAl0aW1lc3RhbXAAAAAAAAAG",
+        "AAAAAQAAACpHb3Zlcm5hbmNlLXNldHRhYmxlIE1hbmEgZGVjYXkgcGFyYW1ldGVycy4AAAAAAAAAAAALRGVjYXlDb25maWcAAAAABQAAAAAAAAAFY3VydmUAAAAAAAfQAAAACkRlY2F5Q3VydmUAAAAAAC9NYW5hIGV2ZXJ5IHJlZ2lzdGVyZWQgYWNjb3VudCBrZWVwcyBhZnRlciBkZWNheQAAAAAFZmxvb3IAAAAAAAAGAAAANkV4cG9uZW50aWFsIGN1cnZlIG9ubHk6IHNlY29uZHMgZm9yIHRoZSBib251cyB0byBoYWx2ZQAAAAAACWhhbGZfbGlmZQAAAAAAAAYAAAA2UGxhdGVhdSBjdXJ2ZSBvbmx5OiBzZWNvbmRzIG9mIGZ1bGwgYm9udXMgYWZ0ZXIgYSBtaW50AAAAAAAHcGxhdGVhdQAAAAAGAAAANVNlY29uZHMgZnJvbSBhIG1pbnQgdW50aWwgaXRzIGJvbnVzIGhhcyBmdWxseSBkZWNheWVkAAAAAAAADnZhY2FuY3lfcGVyaW9kAAAAAAAG",
+        "AAAAAgAAADFXaG8gbWF5IG1pbnQgYmFkZ2VzIG9mIGEgY2F0ZWdvcnkgdGhyb3VnaCBgbWludGAuAAAAAAAAAAAAAApNaW50UG9saWN5AAAAAAAFAAAAAAAAABxPbmx5IHRocm91Z2ggYHNlbGZfcmVnaXN0ZXJgAAAADFNlbGZSZWdpc3RlcgAAAAAAAAART25seSB0aGUgZ292ZXJub3IAAAAAAAAIR292ZXJub3IAAAABAAAAOFRoZSBnb3Zlcm5vciBvciBob2xkZXJzIG9mIGEgYmFkZ2UgaW4gdGhlIGdpdmVuIGNhdGVnb3J5AAAACUhvbGRlcnNPZgAAAAAAAAEAAAAEAAAAAAAAABBBbnkgYmFkZ2UgaG9sZGVyAAAACUFueU1lbWJlcgAAAAAAAAAAAAAhTm90IG1pbnRhYmxlIGFmdGVyIGluaXRpYWxpemF0aW9uAAAAAAAABU5ldmVyAAAA",
+        "AAAAAQAAAEFBIGdvdmVybmFuY2UtZGVmaW5lZCByYW5nZSBvZiB2YWxvciBJRHMgc2hhcmluZyBhIG1pbnRpbmcgcG9saWN5LgAAAAAAAAAAAAAIQ2F0ZWdvcnkAAAAGAAAAAAAAAAJpZAAAAAAABAAAAClMYXN0IHZhbG9yIElEIG9mIHRoZSBjYXRlZ29yeSAoaW5jbHVzaXZlKQAAAAAAAAxtYXhfdmFsb3JfaWQAAAAGAAAAKkZpcnN0IHZhbG9yIElEIG9mIHRoZSBjYXRlZ29yeSAoaW5jbHVzaXZlKQAAAAAADG1pbl92YWxvcl9pZAAAAAYAAAAAAAAAC21pbnRfcG9saWN5AAAAB9AAAAAKTWludFBvbGljeQAAAAAAAAAAAARuYW1lAAAAEAAAADBIaWdoZXN0IHJhcml0eSBhIHZhbG9yIG9mIHRoaXMgY2F0ZWdvcnkgbWF5IGhhdmUAAAAKcmFyaXR5X2NhcAAAAAAABg==",
+        "AAAAAQAAAElBdWRpdCByZWNvcmQgb2YgYSB0b2tlbiByZW1vdmVkIGJ5IGdvdmVybmFuY2Ugb3IgcmVub3VuY2VkIGJ5IGl0cyBob2xkZXIuAAAAAAAAAAAAAApSZXZvY2F0aW9uAAAAAAAFAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAABnJlYXNvbgAAAAAAEAAAADFUcnVlIHdoZW4gdGhlIGhvbGRlciBnYXZlIHVwIHRoZSBiYWRnZSB0aGVtc2VsdmVzAAAAAAAACXJlbm91bmNlZAAAAAAAAAEAAAAAAAAACnJldm9rZWRfYXQAAAAAAAYAAAAAAAAACHZhbG9yX2lkAAAABg==",
+        "AAAAAQAAAEBBIGJhY2tlbmQga2V5IGFsbG93ZWQgdG8gc2lnbiByZWdpc3RyYXRpb25zLCBtaW50cyBhbmQgcmVuZXdhbHMuAAAAAAAAAAZTaWduZXIAAAAAAAMAAAAxVmFsb3IgSURzIHRoaXMga2V5IG1heSBzaWduIGZvcjsgZW1wdHkgYWxsb3dzIGFsbAAAAAAAABFhbGxvd2VkX3ZhbG9yX2lkcwAAAAAAA+oAAAAGAAAARFRpbWVzdGFtcCBhZnRlciB3aGljaCB0aGUga2V5IGlzIG5vIGxvbmdlciBhY2NlcHRlZDsgMCBuZXZlciBleHBpcmVzAAAACmV4cGlyZXNfYXQAAAAAAAYAAAAAAAAACnB1YmxpY19rZXkAAAAAA+4AAAAg",
+        "AAAAAgAAAEdPcGVyYXRpb24gYXV0aG9yaXplZCBieSBhIGJhY2tlbmQgc2lnbmF0dXJlLCB3aXRoIHRoZSBmaWVsZHMgaXQgY292ZXJzLgAAAAAAAAAADFNpZ25lZEFjdGlvbgAAAAMAAAABAAAAFnNlbGZfcmVnaXN0ZXIoYWNjb3VudCkAAAAAAAxTZWxmUmVnaXN0ZXIAAAABAAAAEwAAAAEAAAAgZ3VhcmRpYW5fbWludChhY2NvdW50LCB2YWxvcl9pZCkAAAAMR3VhcmRpYW5NaW50AAAAAgAAABMAAAAGAAAAAQAAAA5yZW5ldyhhY2NvdW50KQAAAAAABVJlbmV3AAAAAAAAAQAAABM=",
+        "AAAAAQAAAHxNZXNzYWdlIHNpZ25lZCBieSB0aGUgYmFja2VuZCwgZW5jb2RlZCBhcyBYRFIuCkJpbmRzIGEgc2lnbmF0dXJlIHRvIG9uZSBkZXBsb3ltZW50IG9uIG9uZSBuZXR3b3JrIGFuZCB0byBhIHNpbmdsZSBvcGVyYXRpb24uAAAAAAAAAA1TaWduZWRNZXNzYWdlAAAAAAAABgAAAAAAAAAGYWN0aW9uAAAAAAfQAAAADFNpZ25lZEFjdGlvbgAAAAAAAAAIY29udHJhY3QAAAATAAAAAAAAAAZleHBpcnkAAAAAAAYAAAAAAAAACm5ldHdvcmtfaWQAAAAAA+4AAAAgAAAAAAAAAAVub25jZQAAAAAAAAYAAAAAAAAAB3ZlcnNpb24AAAAABA==",
+        "AAAAAgAAAB1TdG9yYWdlIGtleXMgZm9yIHRoZSBjb250cmFjdAAAAAAAAAAAAAAHRGF0YUtleQAAAAAjAAAAAAAAABtDb250cmFjdCBpbml0aWFsaXplZCBzdGF0dXMAAAAAC0luaXRpYWxpemVkAAAAAAAAAAAQR292ZXJub3IgYWRkcmVzcwAAAAhHb3Zlcm5vcgAAAAAAAAAQVHJlYXN1cnkgYWRkcmVzcwAAAAhUcmVhc3VyeQAAAAAAAAAMVG90YWwgc3VwcGx5AAAAC1RvdGFsU3VwcGx5AAAAAAAAAAAVTWVtYmVyIGJhZGdlIFZhbG9yIElEAAAAAAAADU1lbWJlclZhbG9ySWQAAAAAAAABAAAAHFRva2VuIElEIHRvIFZhbG9yIElEIG1hcHBpbmcAAAAMVG9rZW5WYWxvcklkAAAAAQAAAAYAAAABAAAAHlZhbG9yIElEIHRvIFZhbG9yIGRhdGEgbWFwcGluZwAAAAAACVZhbG9yRGF0YQAAAAAAAAEAAAAGAAAAAQAAABxBY2NvdW50IHRvIFVzZXJTdGF0cyBtYXBwaW5nAAAACVVzZXJTdGF0cwAAAAAAAAEAAAATAAAAAQAAACFUb2tlbiBJRCB0byBvd25lciBhZGRyZXNzIG1hcHBpbmcAAAAAAAAKVG9rZW5Pd25lcgAAAAAAAQAAAAYAAAAAAAAALVNpbmdsZSBzaWduZXIga2V5IHdyaXR0ZW4gYnkgZWFybGllciB2ZXJzaW9ucwAAAAAAAAZTaWduZXIAAAAAAAEAAAAAAAAACVVzZWROb25jZQAAAAAAAAIAAAATAAAABgAAAAEAAAAyRGVsZWdhdG9yIC0+IGRlbGVnYXRlZSByZWNlaXZpbmcgaXRzIHZvdGluZyB3ZWlnaHQAAAAAAAhEZWxlZ2F0ZQAAAAEAAAATAAAAAQAAADBEZWxlZ2F0ZWUgLT4gYWNjb3VudHMgY3VycmVudGx5IGRlbGVnYXRpbmcgdG8gaXQAAAAKRGVsZWdhdG9ycwAAAAAAAQAAABMAAAABAAAAJkFjY291bnQgLT4gbnVtYmVyIG9mIHN0YXRzIGNoZWNrcG9pbnRzAAAAAAAPQ2hlY2twb2ludENvdW50AAAAAAEAAAATAAAAAQAAACQoQWNjb3VudCwgaW5kZXgpIC0+IHN0YXRzIGNoZWNrcG9pbnQAAAAKQ2hlY2twb2ludAAAAAAAAgAAABMAAAAEAAAAAQAAADhOdW1iZXIgb2YgZGVsZWdhdGlvbiBjaGVja3BvaW50cyByZWNvcmRlZCBmb3IgYW4gYWNjb3VudAAAABlEZWxlZ2F0aW9uQ2hlY2twb2ludENvdW50AAAAAAAAAQAAABMAAAABAAAALERlbGVnYXRpb24gY2hlY2twb2ludCBvZiBhbiBhY2NvdW50IGJ5IGluZGV4AAAAFERlbGVnYXRpb25DaGVja3BvaW50AAAAAgAAABMAAAAEAAAAAQAAAE1BY2NvdW50IC0+IGV4cGlyeSBvZiB0aGUgYmFkZ2VzIGl0IGhlbGQgYmVmb3JlIHBlci1iYWRnZSBncmFudHMgd2VyZSByZWNvcmRlZAAAAAAAAAxMZWdhY3lFeHBpcnkAAAABAAAAEwAAAAAAAAAhTnVtYmVyIG9mIGFjY291bnRzIHdpdGggbGV2ZWwgPiAwAAAAAAAADFRvdGFsTWVtYmVycwAAAAAAAAAZU3VtIG9mIGFsbCBhY2NvdW50IGxldmVscwAAAAAAAApUb3RhbExldmVsAAAAAAAAAAAASFN1bSBvZiBwZXJtYW5lbnQgbGV2ZWxzIGFib3ZlIHRoZSBmbG9vciwgY291bnRlZCBvbiB0b3Agb2YgZGVjYXlpbmcgTWFuYQAAABNUb3RhbFBlcm1hbmVudEV4dHJhAAAAAAEAAABEQWNjb3VudCAtPiBpdHMgcGVybWFuZW50IGxldmVsIGFib3ZlIHRoZSBmbG9vciBpbmNsdWRlZCBpbiB0aGUgdG90YWwAAAAOUGVybWFuZW50RXh0cmEAAAAAAAEAAAATAAAAAQAAADxFeHBpcnkgYnVja2V0IGluZGV4IC0+IGRlY2F5aW5nIE1hbmEgZXhwaXJpbmcgaW4gdGhhdCBidWNrZXQAAAAMRXhwaXJ5QnVja2V0AAAAAQAAAAYAAAAAAAAAIk51bWJlciBvZiB0b3RhbCBzdXBwbHkgY2hlY2twb2ludHMAAAAAABVTdXBwbHlDaGVja3BvaW50Q291bnQAAAAAAAABAAAAIEluZGV4IC0+IHRvdGFsIHN1cHBseSBjaGVja3BvaW50AAAAEFN1cHBseUNoZWNrcG9pbnQAAAABAAAABAAAAAAAAAAkR292ZXJuYW5jZS1zZXQgTWFuYSBkZWNheSBwYXJhbWV0ZXJzAAAAC0RlY2F5Q29uZmlnAAAAAAAAAAAiTnVtYmVyIG9mIGRlY2F5IGNvbmZpZyBjaGVja3BvaW50cwAAAAAAGkRlY2F5Q29uZmlnQ2hlY2twb2ludENvdW50AAAAAAABAAAAIERlY2F5IGNvbmZpZyBjaGVja3BvaW50IGJ5IGluZGV4AAAAFURlY2F5Q29uZmlnQ2hlY2twb2ludAAAAAAAAAEAAAAEAAAAAAAAACxMYXRlc3QgZXhwaXJ5IHJlY29yZGVkIGluIHRoZSBleHBpcnkgYnVja2V0cwAAAAlNYXhFeHBpcnkAAAAAAAABAAAAaEFjY291bnQgLT4gYmFkZ2VzIGhlbGQsIG9sZGVzdCBmaXJzdCwgZWFjaCB3aXRoIGl0cyBvd24gZXhwaXJ5LgpEb3VibGVzIGFzIHRoZSBvd25lciAtPiB0b2tlbiBJRHMgaW5kZXguAAAABkdyYW50cwAAAAAAAQAAABMAAAABAAAAKENhdGVnb3J5IElEIC0+IGJhZGdlIGNhdGVnb3J5IGRlZmluaXRpb24AAAAIQ2F0ZWdvcnkAAAABAAAABAAAAAAAAAAjSURzIG9mIGFsbCBkZWZpbmVkIGJhZGdlIGNhdGVnb3JpZXMAAAAAC0NhdGVnb3J5SWRzAAAAAAEAAAAkVG9rZW4gSUQgLT4gcmVjb3JkIG9mIGl0cyByZXZvY2F0aW9uAAAAClJldm9jYXRpb24AAAAAAAEAAAAGAAAAAAAAACxCYWNrZW5kIHNpZ25lcnMgYWNjZXB0ZWQgYnkgdmVyaWZ5X3NpZ25hdHVyZQAAAAdTaWduZXJzAAAAAAEAAAA5VmFsb3IgSUQgLT4gd2hldGhlciBpdHMgYmFkZ2VzIGFkZCB0byB0aGUgcGVybWFuZW50IGxldmVsAAAAAAAADlBlcm1hbmVudFZhbG9yAAAAAAABAAAABg==" ]),
       options
     )
   }